@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use aoc_input::{Input, InputError};
+
+use crate::get_day_dir;
+
+/// Expected result of a part for a given input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// Name of the input file, in the `files` directory of the day
+    pub file: String,
+    pub expected: String
+}
+
+impl Answer {
+    /// Returns the path of the input file
+    pub fn get_path(&self) -> PathBuf {
+        get_day_dir(self.day).join("files").join(&self.file)
+    }
+}
+
+/// Returns the path of the answers manifest of the workspace
+pub fn get_manifest_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("answers.txt")
+}
+
+/// Parse an answers manifest: one `<day> <part> <file> <expected>` per line, '#' starting a comment
+pub fn parse_answers(input: &Input) -> Result<Vec<Answer>, InputError> {
+    let mut answers: Vec<Answer> = Vec::new();
+    for (index_line, line) in input.lines().into_iter().enumerate() {
+        let line = match line.split_once('#') {
+            Some((before, _)) => before,
+            None => line
+        };
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.is_empty() {
+            continue;
+        }
+        if fields.len() != 4 {
+            return Err(input.error(index_line + 1, "Expected '<day> <part> <file> <expected>'"));
+        }
+        let day = match fields[0].parse::<u8>() {
+            Ok(val) => val,
+            Err(error) => {
+                return Err(input.error(index_line + 1, format!("Invalid day '{}': {error}", fields[0])));
+            }
+        };
+        let part = match fields[1].parse::<u8>() {
+            Ok(val) => val,
+            Err(error) => {
+                return Err(input.error(index_line + 1, format!("Invalid part '{}': {error}", fields[1])));
+            }
+        };
+        answers.push(Answer { day, part, file: fields[2].to_string(), expected: fields[3].to_string() });
+    }
+    Ok(answers)
+}
+
+/// Read the answers manifest of the workspace
+pub fn read_answers() -> Result<Vec<Answer>, InputError> {
+    parse_answers(&Input::read(&get_manifest_path())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_lines_are_parsed() {
+        let input = Input::new("# day part file expected\n\n5 2 input_debug.txt 46\n5 2 input.txt 15290096 # ranges\n");
+        assert_eq!(parse_answers(&input).unwrap(), vec![
+            Answer { day: 5, part: 2, file: "input_debug.txt".to_string(), expected: "46".to_string() },
+            Answer { day: 5, part: 2, file: "input.txt".to_string(), expected: "15290096".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn invalid_lines_are_reported() {
+        assert_eq!(parse_answers(&Input::new("5 2 input.txt")).unwrap_err().to_string(),
+                   "line 1: Expected '<day> <part> <file> <expected>'");
+        assert!(parse_answers(&Input::new("5 2 input.txt 46 slow")).is_err());
+        assert!(parse_answers(&Input::new("five 2 input.txt 46")).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use std::{path::{Path, PathBuf}, time::{Duration, Instant}};
+
+use aoc_input::{Input, InputSource};
+use aoc_solution::{DynSolution, PARTS};
+
+/// Durations measured for a phase (parse or part) of a day
+#[derive(Debug, Clone)]
+pub struct Timings {
+    /// 'parse', 'part1' or 'part2', or 'parse1' and 'parse2' when the parts don't read the same input
+    pub phase: String,
+    /// One duration per iteration, sorted
+    pub durations: Vec<Duration>
+}
+
+impl Timings {
+    pub fn min(&self) -> Duration {
+        self.durations.first().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let len = self.durations.len();
+        if len == 0 {
+            Duration::ZERO
+        }
+        else if len % 2 == 1 {
+            self.durations[len / 2]
+        }
+        else {
+            (self.durations[len / 2 - 1] + self.durations[len / 2]) / 2
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.durations.last().copied().unwrap_or_default()
+    }
+}
+
+/// Time the parsing and the parts of a day over the given number of iterations,
+/// `day_dir` being the directory holding the `files` of the day. Reading the input files isn't timed.
+pub fn bench_day(solution: &dyn DynSolution, source: &InputSource, day_dir: &Path, iterations: usize) -> Result<Vec<Timings>, String> {
+    // Group the parts reading the same input, so that it's parsed once per iteration like with `solve_parts`
+    let mut inputs: Vec<(Option<PathBuf>, Input, Vec<u8>)> = Vec::new();
+    for part in PARTS {
+        let path: Option<PathBuf> = source.get_path(day_dir, part);
+        match inputs.iter_mut().find(|(input_path, _, _)| *input_path == path) {
+            Some((_, _, parts)) => {
+                parts.push(part);
+            }
+            None => {
+                let input = source.load(day_dir, part)?;
+                inputs.push((path, input, vec![part]));
+            }
+        }
+    }
+
+    let mut timings: Vec<Timings> = Vec::new();
+    for (_, _, parts) in &inputs {
+        let phase = if inputs.len() == 1 { "parse".to_string() } else { format!("parse{}", parts[0]) };
+        timings.push(Timings { phase, durations: Vec::with_capacity(iterations) });
+        for part in parts {
+            timings.push(Timings { phase: format!("part{part}"), durations: Vec::with_capacity(iterations) });
+        }
+    }
+
+    for _ in 0..iterations {
+        let mut index_timings: usize = 0;
+        for (_, input, parts) in &inputs {
+            let start = Instant::now();
+            let parsed = match solution.parse(input.content()) {
+                Ok(val) => val,
+                Err(error) => {
+                    return Err(match input.path() {
+                        Some(path) => error.with_path(path),
+                        None => error
+                    }.to_string());
+                }
+            };
+            timings[index_timings].durations.push(start.elapsed());
+            index_timings += 1;
+
+            for part in parts {
+                let start = Instant::now();
+                if let Err(error) = solution.solve(parsed.as_ref(), *part) {
+                    return Err(format!("Error while solving part {part}: {error}"));
+                }
+                timings[index_timings].durations.push(start.elapsed());
+                index_timings += 1;
+            }
+        }
+    }
+
+    for timing in &mut timings {
+        timing.durations.sort();
+    }
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics_of_the_durations() {
+        let timings = Timings {
+            phase: "part1".to_string(),
+            durations: vec![Duration::from_millis(1), Duration::from_millis(2), Duration::from_millis(6), Duration::from_millis(9)]
+        };
+        assert_eq!(timings.min(), Duration::from_millis(1));
+        assert_eq!(timings.median(), Duration::from_millis(4));
+        assert_eq!(timings.max(), Duration::from_millis(9));
+    }
+}
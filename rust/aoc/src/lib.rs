@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+use aoc_solution::{DynSolution, Registered};
+
+pub mod answers;
+pub mod bench;
+
+/// Every day known by the runner as (day, solution)
+pub const DAYS: [(u8, &dyn DynSolution); 10] = [
+    (1, &Registered::<d01::Day01>::new()),
+    (2, &Registered::<d02::Day02>::new()),
+    (3, &Registered::<d03::Day03>::new()),
+    (4, &Registered::<d04::Day04>::new()),
+    (5, &Registered::<d05::Day05>::new()),
+    (6, &Registered::<d06::Day06>::new()),
+    (7, &Registered::<d07::Day07>::new()),
+    (8, &Registered::<d08::Day08>::new()),
+    (9, &Registered::<d09::Day09>::new()),
+    (10, &Registered::<d10::Day10>::new()),
+];
+
+/// Returns the solution of the given day
+pub fn get_solution(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().find(|(sol_day, _)| *sol_day == day).map(|(_, solution)| *solution)
+}
+
+/// Returns the directory of the crate solving the given day, holding its `files`
+pub fn get_day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("d{day:02}"))
+}
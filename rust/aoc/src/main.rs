@@ -0,0 +1,178 @@
+use std::env;
+
+use aoc::{bench::{bench_day, Timings}, get_day_dir, DAYS};
+use aoc_input::{InputSource, INPUT_OPTIONS};
+use aoc_solution::{solve_parts, PARTS};
+
+const USAGE: &str = "Usage: aoc <command> [<input option>]
+
+Commands:
+    run [<day> [<part>]]    Solve a single part, both parts of a day, or every puzzle
+    bench [<day>] [--iterations <n>] [--csv]
+                            Time the parsing and both parts of a day, or of every day, over n iterations (10 by default).
+                            --csv prints 'day,phase,iterations,min_ns,median_ns,max_ns' lines instead of a table
+    list                    List the available puzzles";
+
+/// Number of iterations of `aoc bench` when not given
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// Parse an optional day or part argument, None meaning 'every one of them'
+fn parse_selector(arg: Option<&String>, name: &str) -> Result<Option<u8>, String> {
+    match arg {
+        Some(val) => {
+            match val.parse::<u8>() {
+                Ok(nb) => Ok(Some(nb)),
+                Err(error) => Err(format!("Invalid {name}: '{val}'. Error: {error}")),
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// Solve every puzzle matching the selected day and part
+fn run(day: Option<u8>, part: Option<u8>, source: &InputSource) -> Result<(), String> {
+    // The standard input can only be read once, and a single file rarely fits several days
+    if day.is_none() && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        return Err("A day must be selected when reading a custom input".to_string());
+    }
+    let parts: Vec<u8> = PARTS.into_iter().filter(|val| part.is_none_or(|part| part == *val)).collect();
+
+    let mut nb_run: usize = 0;
+    for (sol_day, solution) in DAYS {
+        if day.is_some_and(|val| val != sol_day) || parts.is_empty() {
+            continue;
+        }
+        match solve_parts(solution, source, &get_day_dir(sol_day), &parts) {
+            Ok(results) => {
+                for (sol_part, result) in results {
+                    println!("Day {sol_day:02} part {sol_part}: {result}");
+                }
+            }
+            Err(error) => {
+                return Err(format!("Error while solving day {sol_day:02}: {error}"));
+            }
+        }
+        nb_run += 1;
+    }
+
+    if nb_run == 0 {
+        return Err(format!("No puzzle found for day {:?} part {:?}", day, part));
+    }
+    Ok(())
+}
+
+/// Time every day matching the selected day, and print the timings as a table or as CSV
+fn bench(day: Option<u8>, iterations: usize, csv: bool, source: &InputSource) -> Result<(), String> {
+    if day.is_none() && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        return Err("A day must be selected when reading a custom input".to_string());
+    }
+    if iterations == 0 {
+        return Err("At least one iteration is required".to_string());
+    }
+
+    if csv {
+        println!("day,phase,iterations,min_ns,median_ns,max_ns");
+    }
+    else {
+        println!("{:<5}{:<8}{:>14}{:>14}{:>14}", "Day", "Phase", "Min", "Median", "Max");
+    }
+    let mut nb_run: usize = 0;
+    for (sol_day, solution) in DAYS {
+        if day.is_some_and(|val| val != sol_day) {
+            continue;
+        }
+        let timings: Vec<Timings> = match bench_day(solution, source, &get_day_dir(sol_day), iterations) {
+            Ok(val) => val,
+            Err(error) => {
+                return Err(format!("Error while benchmarking day {sol_day:02}: {error}"));
+            }
+        };
+        for timing in timings {
+            if csv {
+                println!("{sol_day},{},{iterations},{},{},{}", timing.phase, timing.min().as_nanos(), timing.median().as_nanos(), timing.max().as_nanos());
+            }
+            else {
+                println!("{:<5}{:<8}{:>14}{:>14}{:>14}", format!("{sol_day:02}"), timing.phase,
+                         format!("{:.2?}", timing.min()), format!("{:.2?}", timing.median()), format!("{:.2?}", timing.max()));
+            }
+        }
+        nb_run += 1;
+    }
+
+    if nb_run == 0 {
+        return Err(format!("No puzzle found for day {:?}", day));
+    }
+    Ok(())
+}
+
+/// Parse the arguments of `aoc bench`, and run it
+fn parse_bench_args(args: &[String], source: &InputSource) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut iterations: usize = DEFAULT_ITERATIONS;
+    let mut csv = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                iterations = match args.next().map(|val| (val, val.parse::<usize>())) {
+                    Some((_, Ok(val))) => val,
+                    Some((val, Err(error))) => {
+                        return Err(format!("Invalid number of iterations: '{val}'. Error: {error}"));
+                    }
+                    None => {
+                        return Err("Missing <n> after '--iterations'".to_string());
+                    }
+                };
+            }
+            "--csv" => {
+                csv = true;
+            }
+            _ if day.is_none() => {
+                day = parse_selector(Some(arg), "day")?;
+            }
+            _ => {
+                return Err(format!("Unexpected argument: '{arg}'\n\n{USAGE}"));
+            }
+        }
+    }
+    bench(day, iterations, csv, source)
+}
+
+fn main() -> Result<(), i8> {
+    let (source, args): (InputSource, Vec<String>) = match InputSource::from_args(env::args().skip(1)) {
+        Ok(val) => val,
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}\n\n{INPUT_OPTIONS}");
+            return Err(-1);
+        }
+    };
+
+    let res = match args.first().map(|arg| arg.as_str()) {
+        Some("run") if args.len() <= 3 => {
+            match (parse_selector(args.get(1), "day"), parse_selector(args.get(2), "part")) {
+                (Ok(day), Ok(part)) => run(day, part, &source),
+                (Err(error), _) | (_, Err(error)) => Err(error),
+            }
+        }
+        Some("bench") => parse_bench_args(&args[1..], &source),
+        Some("list") => {
+            for (day, _) in DAYS {
+                for part in PARTS {
+                    println!("Day {day:02} part {part}");
+                }
+            }
+            Ok(())
+        }
+        _ => {
+            Err(format!("{USAGE}\n\n{INPUT_OPTIONS}"))
+        }
+    };
+
+    match res {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            eprintln!("{error}");
+            Err(-1)
+        }
+    }
+}
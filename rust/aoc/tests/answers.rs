@@ -0,0 +1,53 @@
+use aoc::{answers::{read_answers, Answer}, get_day_dir, get_solution, DAYS};
+use aoc_input::InputSource;
+use aoc_solution::{solve_parts, PARTS};
+
+/// Solve every answer of the manifest, and returns a description of the wrong ones
+fn check_answers(answers: &[Answer]) -> Vec<String> {
+    let mut failures: Vec<String> = Vec::new();
+    for answer in answers {
+        let solution = match get_solution(answer.day) {
+            Some(val) => val,
+            None => {
+                failures.push(format!("Day {:02}: no solution registered", answer.day));
+                continue;
+            }
+        };
+        let source = InputSource::File(answer.get_path());
+        match solve_parts(solution, &source, &get_day_dir(answer.day), &[answer.part]) {
+            Ok(results) => {
+                let result = &results[0].1;
+                if *result != answer.expected {
+                    failures.push(format!("Day {:02} part {} ({}): expected {}, got {result}", answer.day, answer.part, answer.file, answer.expected));
+                }
+            }
+            Err(error) => {
+                failures.push(format!("Day {:02} part {} ({}): {error}", answer.day, answer.part, answer.file));
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn every_part_has_an_example_and_a_puzzle_answer() {
+    let answers = read_answers().unwrap();
+    for (day, _) in DAYS {
+        for part in PARTS {
+            let files: Vec<&str> = answers.iter()
+                .filter(|answer| answer.day == day && answer.part == part)
+                .map(|answer| answer.file.as_str())
+                .collect();
+            assert!(files.iter().any(|file| file.starts_with("input_debug")), "Day {day:02} part {part}: no example answer");
+            assert!(files.contains(&"input.txt"), "Day {day:02} part {part}: no puzzle answer");
+        }
+    }
+}
+
+#[test]
+fn answers_are_still_found() {
+    let answers: Vec<Answer> = read_answers().unwrap();
+    assert!(!answers.is_empty(), "No answer in the manifest");
+    let failures = check_answers(&answers);
+    assert!(failures.is_empty(), "Wrong answers:\n{}", failures.join("\n"));
+}
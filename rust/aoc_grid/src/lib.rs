@@ -0,0 +1,248 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use aoc_input::{Input, InputError};
+
+/// Offsets of the orthogonal neighbors: north, east, south, west
+pub const NEIGHBORS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbors, clockwise from the north
+pub const NEIGHBORS_8: [(i64, i64); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A dense rectangular grid stored row by row, (0, 0) being the top left cell and y growing downwards
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its cells, row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!("A {width}x{height} grid needs {} cells, got {}", width * height, cells.len()));
+        }
+        Ok(Self { width, height, cells })
+    }
+
+    /// Build a grid from its rows, which must all have the same length, else returns the index of the first
+    /// row that doesn't and the error message
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, (usize, String)> {
+        let width: usize = rows.first().map_or(0, |row| row.len());
+        let height: usize = rows.len();
+        let mut cells: Vec<T> = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err((y, format!("Row {y} has {} cells, expected {width}", row.len())));
+            }
+            cells.extend(row);
+        }
+        Ok(Self { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the position of the cell at the given signed coordinates, if it's within the grid
+    pub fn position(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let x = usize::try_from(x).ok()?;
+        let y = usize::try_from(y).ok()?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the positions of the neighbors found at the given offsets, skipping the ones outside of the grid
+    pub fn neighbors(&self, x: usize, y: usize, offsets: &'static [(i64, i64)]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| self.position(x as i64 + dx, y as i64 + dy))
+    }
+
+    /// Returns the positions of the orthogonal neighbors within the grid
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS_4)
+    }
+
+    /// Returns the positions of the orthogonal and diagonal neighbors within the grid
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS_8)
+    }
+
+    /// Returns the cells of the row y
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        }
+        else {
+            None
+        }
+    }
+
+    /// Returns the rows from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Returns the cells of the column x, from the top
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Returns every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    /// Returns every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns a grid of the same size, with f applied on every cell
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where F: FnMut(&T) -> U
+    {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Build a grid with every cell set to value
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Returns the grid mirrored along its top left to bottom right diagonal
+    pub fn transpose(&self) -> Self {
+        let cells: Vec<T> = (0..self.width).flat_map(|x| self.column(x).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    /// Returns the grid rotated by a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells: Vec<T> = (0..self.width).flat_map(|x| self.column(x).rev().cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    /// Returns the grid rotated by a quarter turn counterclockwise
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let cells: Vec<T> = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+}
+
+impl Grid<char> {
+    /// Parse a map of chars, every line being a row of the same length
+    pub fn parse(input: &Input) -> Result<Self, InputError> {
+        let rows: Vec<Vec<char>> = input.char_grid()?;
+        match Self::from_rows(rows) {
+            Ok(grid) => Ok(grid),
+            Err((y, error)) => Err(input.error(y + 1, error))
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width && y < self.height, "({x}, {y}) is outside of the {}x{} grid", self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width && y < self.height, "({x}, {y}) is outside of the {}x{} grid", self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Writes every row on its own line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> Grid<char> {
+        Grid::parse(&Input::new("abc\ndef\n")).unwrap()
+    }
+
+    #[test]
+    fn char_maps_are_parsed_and_displayed() {
+        let grid = get_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert!(Grid::parse(&Input::new("abc\nde\n")).is_err());
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5]]), Err((2, "Row 2 has 1 cells, expected 2".to_string())));
+    }
+
+    #[test]
+    fn neighbors_stay_within_the_grid() {
+        let grid = get_grid();
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8(1, 0).collect::<Vec<_>>(), vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+        assert_eq!(grid.position(-1, 0), None);
+    }
+
+    #[test]
+    fn rows_and_columns_are_sliced() {
+        let grid = get_grid();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn grids_are_transposed_and_rotated() {
+        let grid = get_grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,261 @@
+use std::{error::Error, fmt, fs::read_to_string, io::{self, Read}, path::{Path, PathBuf}, str::FromStr};
+
+mod source;
+pub use source::{InputSource, INPUT_OPTIONS};
+
+/// Error raised while loading or parsing a puzzle input
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file couldn't be read
+    Io {
+        path: PathBuf,
+        error: io::Error
+    },
+    /// A line of the input couldn't be parsed, `line` starts at 1
+    Parse {
+        path: Option<PathBuf>,
+        line: usize,
+        message: String
+    }
+}
+
+impl InputError {
+    /// Returns a parse error for the given line (starting at 1), not attached to any file yet
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            path: None,
+            line,
+            message: message.into()
+        }
+    }
+
+    /// Attach the file the error comes from, if it isn't already known
+    pub fn with_path(self, new_path: &Path) -> Self {
+        match self {
+            Self::Parse { path: None, line, message } => {
+                Self::Parse { path: Some(new_path.to_path_buf()), line, message }
+            }
+            other => other
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => {
+                write!(f, "Error while reading '{}': {error}", path.display())
+            }
+            Self::Parse { path: Some(path), line, message } => {
+                write!(f, "{}:{line}: {message}", path.display())
+            }
+            Self::Parse { path: None, line, message } => {
+                write!(f, "line {line}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse { .. } => None
+        }
+    }
+}
+
+impl From<InputError> for String {
+    fn from(error: InputError) -> Self {
+        error.to_string()
+    }
+}
+
+/// A block of consecutive non blank lines
+#[derive(Debug, PartialEq, Eq)]
+pub struct Paragraph<'a> {
+    /// Number of the first line of the block, starting at 1
+    pub first_line: usize,
+    pub lines: Vec<&'a str>
+}
+
+/// The content of a puzzle input, along with the file it comes from
+#[derive(Debug, Clone)]
+pub struct Input {
+    path: Option<PathBuf>,
+    content: String
+}
+
+impl Input {
+    /// Wrap an already loaded content
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            path: None,
+            content: content.into()
+        }
+    }
+
+    /// Read the whole input file
+    pub fn read(path: &Path) -> Result<Self, InputError> {
+        match read_to_string(path) {
+            Ok(content) => Ok(Self { path: Some(path.to_path_buf()), content }),
+            Err(error) => Err(InputError::Io { path: path.to_path_buf(), error })
+        }
+    }
+
+    /// Read the whole standard input
+    pub fn from_stdin() -> Result<Self, InputError> {
+        let mut content = String::new();
+        match io::stdin().read_to_string(&mut content) {
+            Ok(_) => Ok(Self::new(content)),
+            Err(error) => Err(InputError::Io { path: PathBuf::from("<stdin>"), error })
+        }
+    }
+
+    /// The file the input was read from, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The raw content of the input
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Returns a parse error for the given line (starting at 1), attached to the input file
+    pub fn error(&self, line: usize, message: impl Into<String>) -> InputError {
+        InputError::Parse {
+            path: self.path.clone(),
+            line,
+            message: message.into()
+        }
+    }
+
+    /// Returns the lines of the input, without line terminators and without the trailing empty line
+    pub fn lines(&self) -> Vec<&str> {
+        self.content.lines().collect()
+    }
+
+    /// Returns the blocks of lines separated by blank lines
+    pub fn paragraphs(&self) -> Vec<Paragraph<'_>> {
+        let mut paragraphs = Vec::<Paragraph>::new();
+        let mut current: Option<Paragraph> = None;
+        for (index_line, line) in self.content.lines().enumerate() {
+            if line.trim().is_empty() {
+                if let Some(paragraph) = current.take() {
+                    paragraphs.push(paragraph);
+                }
+            }
+            else {
+                current.get_or_insert(Paragraph { first_line: index_line + 1, lines: Vec::new() }).lines.push(line);
+            }
+        }
+        if let Some(paragraph) = current {
+            paragraphs.push(paragraph);
+        }
+        paragraphs
+    }
+
+    /// Returns the input as a rectangular grid of chars, indexed as [y][x]
+    pub fn char_grid(&self) -> Result<Vec<Vec<char>>, InputError> {
+        let mut grid = Vec::<Vec<char>>::new();
+        for (index_line, line) in self.content.lines().enumerate() {
+            let row: Vec<char> = line.chars().collect();
+            if let Some(first_row) = grid.first() {
+                if row.len() != first_row.len() {
+                    return Err(self.error(index_line + 1, format!("Expected {} columns, found {}", first_row.len(), row.len())));
+                }
+            }
+            grid.push(row);
+        }
+        Ok(grid)
+    }
+
+    /// Returns the whitespace separated numbers of every line
+    pub fn numbers<T>(&self) -> Result<Vec<Vec<T>>, InputError>
+    where T: FromStr, T::Err: fmt::Display
+    {
+        let mut numbers = Vec::<Vec<T>>::new();
+        for (index_line, line) in self.content.lines().enumerate() {
+            numbers.push(parse_numbers(line).map_err(|message| self.error(index_line + 1, message))?);
+        }
+        Ok(numbers)
+    }
+}
+
+/// Parse every whitespace separated number of a str
+pub fn parse_numbers<T>(text: &str) -> Result<Vec<T>, String>
+where T: FromStr, T::Err: fmt::Display
+{
+    let mut numbers = Vec::<T>::new();
+    for nb in text.split_whitespace() {
+        match nb.parse::<T>() {
+            Ok(val) => {
+                numbers.push(val);
+            }
+            Err(error) => {
+                return Err(format!("Couldn't parse '{nb}' as a number: {error}"));
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_ignore_trailing_newline() {
+        let input = Input::new("a\nb\r\nc\n");
+        assert_eq!(input.lines(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn paragraphs_keep_first_line_number() {
+        let input = Input::new("seeds: 1 2\n\nmap:\n1 2 3\n\n\nmap:\n4 5 6\n");
+        let paragraphs = input.paragraphs();
+        assert_eq!(paragraphs.len(), 3);
+        assert_eq!(paragraphs[0], Paragraph { first_line: 1, lines: vec!["seeds: 1 2"] });
+        assert_eq!(paragraphs[1], Paragraph { first_line: 3, lines: vec!["map:", "1 2 3"] });
+        assert_eq!(paragraphs[2], Paragraph { first_line: 7, lines: vec!["map:", "4 5 6"] });
+    }
+
+    #[test]
+    fn char_grid_is_rectangular() {
+        let input = Input::new("ab\ncd\n");
+        assert_eq!(input.char_grid().unwrap(), vec![vec!['a', 'b'], vec!['c', 'd']]);
+
+        let input = Input::new("ab\ncd\ne\n");
+        match input.char_grid() {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 3),
+            other => panic!("Expected a parse error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn numbers_report_offending_line() {
+        let input = Input::new("0 3 6 9\n-1 -2\n");
+        assert_eq!(input.numbers::<i64>().unwrap(), vec![vec![0, 3, 6, 9], vec![-1, -2]]);
+
+        let input = Input::new("1 2\n3 x\n");
+        match input.numbers::<u8>() {
+            Err(InputError::Parse { line, message, .. }) => {
+                assert_eq!(line, 2);
+                assert!(message.contains("'x'"));
+            }
+            other => panic!("Expected a parse error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn errors_carry_the_path() {
+        let error = InputError::parse(4, "bad").with_path(Path::new("files/input.txt"));
+        assert_eq!(error.to_string(), "files/input.txt:4: bad");
+
+        match Input::read(Path::new("does/not/exist.txt")) {
+            Err(InputError::Io { path, .. }) => assert_eq!(path, Path::new("does/not/exist.txt")),
+            other => panic!("Expected an io error, got {:?}", other)
+        }
+    }
+}
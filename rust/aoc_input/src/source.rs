@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+
+use crate::{Input, InputError};
+
+/// Help text of the options parsed by `InputSource::from_args`
+pub const INPUT_OPTIONS: &str = "Input options:
+    --input <path>    Read the puzzle input from the given file
+    --example         Use the example of the puzzle statement (files/input_debug.txt,
+                      or files/input_debug_<part>.txt when the parts have different examples)
+    -                 Read the puzzle input from the standard input
+    (default)         Use the puzzle input of the day (files/input.txt)";
+
+/// Where the puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `files/input.txt` of the day
+    #[default]
+    Default,
+    /// `files/input_debug.txt` of the day, holding the example of the puzzle statement,
+    /// or `files/input_debug_<part>.txt` when the parts don't share the same example
+    Example,
+    /// Any file, relative paths being resolved from the working directory
+    File(PathBuf),
+    /// The standard input
+    Stdin
+}
+
+impl InputSource {
+    /// Extract the input options from the command line arguments, and returns the other arguments untouched
+    pub fn from_args<I>(args: I) -> Result<(Self, Vec<String>), String>
+    where I: IntoIterator<Item = String>
+    {
+        let mut source: Option<Self> = None;
+        let mut others: Vec<String> = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let new_source = match arg.as_str() {
+                "--input" => {
+                    match args.next() {
+                        Some(path) => Self::File(PathBuf::from(path)),
+                        None => {
+                            return Err("Missing <path> after '--input'".to_string());
+                        }
+                    }
+                }
+                "--example" => Self::Example,
+                "-" => Self::Stdin,
+                _ => {
+                    others.push(arg);
+                    continue;
+                }
+            };
+            if let Some(previous) = &source {
+                return Err(format!("Only one input can be selected, got {:?} and {:?}", previous, new_source));
+            }
+            source = Some(new_source);
+        }
+        Ok((source.unwrap_or_default(), others))
+    }
+
+    /// Returns the file to read for the given part, `day_dir` being the directory holding the `files` of the day
+    pub fn get_path(&self, day_dir: &Path, part: u8) -> Option<PathBuf> {
+        match self {
+            Self::Default => Some(day_dir.join("files").join("input.txt")),
+            Self::Example => {
+                let part_example = day_dir.join("files").join(format!("input_debug_{part}.txt"));
+                if part_example.is_file() {
+                    Some(part_example)
+                }
+                else {
+                    Some(day_dir.join("files").join("input_debug.txt"))
+                }
+            }
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None
+        }
+    }
+
+    /// Load the selected input for the given part, `day_dir` being the directory holding the `files` of the day
+    pub fn load(&self, day_dir: &Path, part: u8) -> Result<Input, InputError> {
+        match self.get_path(day_dir, part) {
+            Some(path) => Input::read(&path),
+            None => Input::from_stdin()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_source_keeps_other_args() {
+        let (source, others) = InputSource::from_args(args(&["run", "5", "2"])).unwrap();
+        assert_eq!(source, InputSource::Default);
+        assert_eq!(others, args(&["run", "5", "2"]));
+    }
+
+    #[test]
+    fn options_select_the_source() {
+        let (source, others) = InputSource::from_args(args(&["run", "--input", "other.txt", "5"])).unwrap();
+        assert_eq!(source, InputSource::File(PathBuf::from("other.txt")));
+        assert_eq!(others, args(&["run", "5"]));
+
+        assert_eq!(InputSource::from_args(args(&["--example"])).unwrap().0, InputSource::Example);
+        assert_eq!(InputSource::from_args(args(&["-"])).unwrap().0, InputSource::Stdin);
+    }
+
+    #[test]
+    fn invalid_options_are_rejected() {
+        assert!(InputSource::from_args(args(&["--input"])).is_err());
+        assert!(InputSource::from_args(args(&["--example", "-"])).is_err());
+    }
+
+    #[test]
+    fn paths_do_not_depend_on_the_working_directory() {
+        let day_dir = Path::new("/somewhere/d05");
+        assert_eq!(InputSource::Default.get_path(day_dir, 1), Some(day_dir.join("files").join("input.txt")));
+        assert_eq!(InputSource::Example.get_path(day_dir, 2), Some(day_dir.join("files").join("input_debug.txt")));
+        assert_eq!(InputSource::Stdin.get_path(day_dir, 1), None);
+    }
+}
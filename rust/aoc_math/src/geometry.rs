@@ -0,0 +1,284 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::{PrimInt, Signed};
+
+/// A point (or a vector) of the plane, with (0, 0) on the top left corner and y growing downwards
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy, Default, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T: PrimInt> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self {
+            x,
+            y
+        }
+    }
+
+    /// Number of horizontal and vertical steps between self and other
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Number of king moves between self and other, diagonals included
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: PrimInt + Signed> Point2<T> {
+    /// The point one step away in the given direction
+    pub fn step(&self, direction: Direction) -> Self {
+        *self + direction.offset()
+    }
+
+    /// The 4 points one step away, in the order of `Direction::ALL`
+    pub fn neighbors4(&self) -> [Self; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+}
+
+/// |a - b|, without overflowing on unsigned types
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T: PrimInt> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: PrimInt> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: PrimInt> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// Scales the vector
+impl<T: PrimInt> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+/// Represents a direction, its the direction pointed by the vector
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+pub enum Direction {
+    /// '^'
+    North,
+    /// '>'
+    East,
+    /// 'v'
+    South,
+    /// '<'
+    West
+}
+
+impl Direction {
+    /// Every direction, clockwise from North
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// The direction after a quarter turn clockwise
+    pub fn turn_right(&self) -> Self {
+        match *self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise
+    pub fn turn_left(&self) -> Self {
+        match *self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match *self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East
+        }
+    }
+
+    /// The vector of one step in this direction, North being towards y = 0
+    pub fn offset<T: PrimInt + Signed>(&self) -> Point2<T> {
+        match *self {
+            Self::North => Point2::new(T::zero(), -T::one()),
+            Self::East => Point2::new(T::one(), T::zero()),
+            Self::South => Point2::new(T::zero(), T::one()),
+            Self::West => Point2::new(-T::one(), T::zero())
+        }
+    }
+
+    /// The direction of a one step vector, None for any other vector
+    pub fn from_offset<T: PrimInt + Signed>(offset: Point2<T>) -> Option<Self> {
+        Self::ALL.into_iter().find(|direction| direction.offset::<T>() == offset)
+    }
+
+    /// The arrow char pointing in this direction
+    pub fn get_char(&self) -> char {
+        match *self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<'
+        }
+    }
+}
+
+/// The smallest rectangle containing a set of points, both corners included
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>
+}
+
+impl<T: PrimInt> BoundingBox<T> {
+    /// Returns None if there are no points
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point2<T>>) -> Option<Self> where T: 'a {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut res = Self { min: first, max: first };
+        for point in points {
+            res.extend(point);
+        }
+        Some(res)
+    }
+
+    /// Grows the box until it contains the point
+    pub fn extend(&mut self, point: &Point2<T>) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: &Point2<T>) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x && self.min.y <= point.y && point.y <= self.max.y
+    }
+
+    /// Number of columns covered by the box
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::one()
+    }
+
+    /// Number of rows covered by the box
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::one()
+    }
+}
+
+/// Twice the area of the polygon whose vertices are given in the walking order (shoelace formula).
+/// Doubled to stay on integers, the polygon being closed from the last vertex back to the first.
+/// The area of a polygon with less than 3 vertices, or with all of them on a line, is 0
+pub fn polygon_double_area<T: PrimInt + Signed>(vertices: &[Point2<T>]) -> T {
+    let mut double_area = T::zero();
+    for (index, vertex) in vertices.iter().enumerate() {
+        let next_vertex = vertices[(index + 1) % vertices.len()];
+        double_area = double_area + vertex.x * next_vertex.y - next_vertex.x * vertex.y;
+    }
+    double_area.abs()
+}
+
+/// Number of lattice points strictly inside a polygon with lattice vertices, from Pick's theorem
+/// (area = inside + boundary / 2 - 1), `boundary` being the number of lattice points on its edges
+pub fn interior_points<T: PrimInt + Signed>(double_area: T, boundary: T) -> T {
+    let two = T::one() + T::one();
+    (double_area - boundary + two) / two
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_turn_back_on_themselves() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.offset::<i64>() + direction.opposite().offset(), Point2::new(0, 0));
+            assert_eq!(Direction::from_offset(direction.offset::<i32>()), Some(direction));
+        }
+        assert_eq!(Direction::from_offset(Point2::new(1, 1)), None);
+    }
+
+    #[test]
+    fn distances_work_on_unsigned_points() {
+        let a: Point2<u32> = Point2::new(1, 7);
+        let b: Point2<u32> = Point2::new(4, 3);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(b.manhattan_distance(&a), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+    }
+
+    #[test]
+    fn area_of_a_square_loop() {
+        // The border of a 3x3 square, walked clockwise: 8 boundary points around a single one
+        let mut vertices: Vec<Point2<i64>> = Vec::new();
+        let mut current = Point2::new(0, 0);
+        for direction in Direction::ALL.iter().cycle().skip(1).take(4) {
+            for _ in 0..2 {
+                vertices.push(current);
+                current = current.step(*direction);
+            }
+        }
+        let double_area = polygon_double_area(&vertices);
+        assert_eq!(double_area, 8);
+        assert_eq!(interior_points(double_area, vertices.len() as i64), 1);
+
+        assert_eq!(polygon_double_area::<i64>(&[]), 0);
+        assert_eq!(polygon_double_area(&vertices[..1]), 0);
+        assert_eq!(polygon_double_area(&[Point2::new(0, 0), Point2::new(1, 1), Point2::new(3, 3)]), 0);
+
+        let bounding_box = BoundingBox::from_points(&vertices).unwrap();
+        assert_eq!((bounding_box.width(), bounding_box.height()), (3, 3));
+        assert!(bounding_box.contains(&Point2::new(1, 1)));
+        assert!(!bounding_box.contains(&Point2::new(3, 1)));
+    }
+}
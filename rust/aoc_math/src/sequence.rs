@@ -0,0 +1,204 @@
+use std::fmt;
+
+use num::{BigInt, BigRational, One, Signed, Zero};
+
+/// Returns the sequence followed by its successive differences, down to the first one with only 0
+pub fn get_differences(sequence: &[i64]) -> Vec<Vec<i64>> {
+    let mut processed_sequences = Vec::<Vec<i64>>::new();
+    let mut current_sequence = sequence.to_vec();
+    loop {
+        let next_sequence: Vec<i64> = current_sequence.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let finished = next_sequence.iter().all(|nb| *nb == 0);
+        processed_sequences.push(current_sequence);
+        if finished {
+            return processed_sequences;
+        }
+        current_sequence = next_sequence;
+    }
+}
+
+/// Extrapolate the `k` values following the sequence
+pub fn extrapolate_next(sequence: &[i64], k: usize) -> Vec<i64> {
+    // Last value of every difference row, extended one column at a time from the bottom row
+    let mut last_values: Vec<i64> = get_differences(sequence).iter().filter_map(|row| row.last().copied()).collect();
+    let mut res = Vec::<i64>::with_capacity(k);
+    for _ in 0..k {
+        for index_row in (0..last_values.len().saturating_sub(1)).rev() {
+            last_values[index_row] += last_values[index_row + 1];
+        }
+        res.push(last_values.first().copied().unwrap_or(0));
+    }
+    res
+}
+
+/// Extrapolate the `k` values preceding the sequence, the closest one first
+pub fn extrapolate_previous(sequence: &[i64], k: usize) -> Vec<i64> {
+    let mut first_values: Vec<i64> = get_differences(sequence).iter().filter_map(|row| row.first().copied()).collect();
+    let mut res = Vec::<i64>::with_capacity(k);
+    for _ in 0..k {
+        for index_row in (0..first_values.len().saturating_sub(1)).rev() {
+            first_values[index_row] -= first_values[index_row + 1];
+        }
+        res.push(first_values.first().copied().unwrap_or(0));
+    }
+    res
+}
+
+/// Closed form of a sequence: the lowest degree polynomial P such that P(i) is the i-th value of the sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// Δ^k of the first value, P(x) being the sum of Δ^k * C(x, k): the Newton forward form
+    newton_coefficients: Vec<BigInt>,
+    /// Coefficient of x^k, at index k
+    coefficients: Vec<BigRational>
+}
+
+impl Polynomial {
+    /// Fit the polynomial on the sequence, sampled at x = 0, 1, 2...
+    pub fn fit(sequence: &[i64]) -> Self {
+        let mut newton_coefficients: Vec<BigInt> = get_differences(sequence).iter()
+            .filter_map(|row| row.first().map(|nb| BigInt::from(*nb)))
+            .collect();
+        while newton_coefficients.last().is_some_and(|nb| nb.is_zero()) {
+            newton_coefficients.pop();
+        }
+
+        // C(x, k) = x(x - 1)...(x - k + 1) / k!, built one factor at a time
+        let mut coefficients: Vec<BigRational> = vec![BigRational::zero(); newton_coefficients.len()];
+        let mut falling_factorial: Vec<BigRational> = vec![BigRational::one()];
+        let mut factorial = BigInt::one();
+        for (k, newton_coefficient) in newton_coefficients.iter().enumerate() {
+            if k > 0 {
+                factorial *= BigInt::from(k);
+                let root = BigRational::from_integer(BigInt::from(k - 1));
+                let mut next_factorial: Vec<BigRational> = vec![BigRational::zero(); k + 1];
+                for (power, coefficient) in falling_factorial.iter().enumerate() {
+                    next_factorial[power + 1] += coefficient;
+                    next_factorial[power] -= coefficient * &root;
+                }
+                falling_factorial = next_factorial;
+            }
+            let scale = BigRational::new(newton_coefficient.clone(), factorial.clone());
+            for (power, coefficient) in falling_factorial.iter().enumerate() {
+                coefficients[power] += coefficient * &scale;
+            }
+        }
+
+        Self { newton_coefficients, coefficients }
+    }
+
+    /// Degree of the polynomial, None for the null polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Coefficient of x^k at index k
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    /// Δ^k of the first value of the sequence at index k
+    pub fn newton_coefficients(&self) -> &[BigInt] {
+        &self.newton_coefficients
+    }
+
+    /// Returns P(x), using Horner's method
+    pub fn evaluate(&self, x: &BigRational) -> BigRational {
+        self.coefficients.iter().rev().fold(BigRational::zero(), |res, coefficient| res * x + coefficient)
+    }
+
+    /// Returns the value of the sequence at the given index, negative indexes preceding the sequence
+    pub fn value_at(&self, index: i64) -> BigRational {
+        self.evaluate(&BigRational::from_integer(BigInt::from(index)))
+    }
+
+    /// Returns the Newton forward form, e.g. `1 + 2*C(x, 1) + 1*C(x, 2)`
+    pub fn newton_form(&self) -> String {
+        let terms: Vec<String> = self.newton_coefficients.iter().enumerate()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(k, coefficient)| if k == 0 { coefficient.to_string() } else { format!("{coefficient}*C(x, {k})") })
+            .collect();
+        if terms.is_empty() {
+            "0".to_string()
+        }
+        else {
+            terms.join(" + ")
+        }
+    }
+}
+
+/// Writes the polynomial from the highest power, e.g. `1/2*x^2 + 1/2*x - 3`
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut is_first = true;
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+            let sign = if coefficient.is_negative() { "-" } else { "+" };
+            if is_first {
+                if coefficient.is_negative() {
+                    write!(f, "-")?;
+                }
+            }
+            else {
+                write!(f, " {sign} ")?;
+            }
+            is_first = false;
+
+            let abs_coefficient = coefficient.abs();
+            match power {
+                0 => write!(f, "{abs_coefficient}")?,
+                _ if abs_coefficient.is_one() => write!(f, "x")?,
+                _ => write!(f, "{abs_coefficient}*x")?
+            }
+            if power > 1 {
+                write!(f, "^{power}")?;
+            }
+        }
+        if is_first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(BigInt::from(numer), BigInt::from(denom))
+    }
+
+    #[test]
+    fn values_are_extrapolated() {
+        let sequence = [10, 13, 16, 21, 30, 45];
+        assert_eq!(extrapolate_next(&sequence, 2), vec![68, 101]);
+        assert_eq!(extrapolate_previous(&sequence, 2), vec![5, -4]);
+        assert_eq!(extrapolate_next(&[7, 7, 7], 1), vec![7]);
+        assert_eq!(extrapolate_next(&[], 1), vec![0]);
+    }
+
+    #[test]
+    fn triangular_numbers_are_fitted() {
+        let polynomial = Polynomial::fit(&[0, 1, 3, 6, 10]);
+        assert_eq!(polynomial.degree(), Some(2));
+        assert_eq!(polynomial.coefficients(), &[ratio(0, 1), ratio(1, 2), ratio(1, 2)]);
+        assert_eq!(polynomial.to_string(), "1/2*x^2 + 1/2*x");
+        assert_eq!(polynomial.newton_form(), "1*C(x, 1) + 1*C(x, 2)");
+        assert_eq!(polynomial.value_at(5), ratio(15, 1));
+        assert_eq!(polynomial.evaluate(&ratio(1, 2)), ratio(3, 8));
+    }
+
+    #[test]
+    fn fitted_polynomial_matches_the_extrapolation() {
+        let sequence = [10, 13, 16, 21, 30, 45];
+        let polynomial = Polynomial::fit(&sequence);
+        assert_eq!(polynomial.degree(), Some(3));
+        assert_eq!(polynomial.value_at(6), ratio(68, 1));
+        assert_eq!(polynomial.value_at(-1), ratio(5, 1));
+        assert_eq!(Polynomial::fit(&[0, 0]).degree(), None);
+        assert_eq!(Polynomial::fit(&[-3, -3]).to_string(), "-3");
+    }
+}
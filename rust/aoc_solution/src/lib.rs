@@ -0,0 +1,253 @@
+use std::{any::Any, env, marker::PhantomData, path::{Path, PathBuf}};
+
+use aoc_input::{InputError, InputSource, INPUT_OPTIONS};
+
+/// Parts of every puzzle
+pub const PARTS: [u8; 2] = [1, 2];
+
+/// Solver of a day, both parts working on the same parsed input
+pub trait Solution {
+    /// The puzzle input once parsed
+    type Input;
+
+    /// Parse the raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input, InputError>;
+
+    /// Solve the first part of the puzzle
+    fn part1(input: &Self::Input) -> Result<String, String>;
+
+    /// Solve the second part of the puzzle
+    fn part2(input: &Self::Input) -> Result<String, String>;
+}
+
+/// Solution whose parts can be tuned from the command line of the day binary
+pub trait Configurable: Solution {
+    /// How the parts are solved, `Default` being what `Solution::part1` and `Solution::part2` use
+    type Options: Default;
+
+    /// Help text of the options parsed by `parse_options`
+    const OPTIONS: &'static str;
+
+    /// Parse the command line arguments that aren't input options
+    fn parse_options(args: &[String]) -> Result<Self::Options, String>;
+
+    /// Solve the first part of the puzzle with the given options
+    fn part1_with(input: &Self::Input, options: &Self::Options) -> Result<String, String>;
+
+    /// Solve the second part of the puzzle with the given options
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String>;
+
+    /// Report asked for by the options on how a part is solved (traces, statistics...), which the day binary
+    /// prints to stderr after the answers. The parts stay silent, so that `aoc run` and `aoc bench` only
+    /// show the answers. Empty by default
+    fn get_report(_input: &Self::Input, _options: &Self::Options, _part: u8) -> Result<String, String> {
+        Ok(String::new())
+    }
+}
+
+/// Object safe version of `Solution`, so that days with different inputs can be stored together
+pub trait DynSolution: Sync {
+    /// Parse the raw puzzle input into the `Solution::Input` of the day
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, InputError>;
+
+    /// Solve a part from an input returned by `parse`
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String, String>;
+
+    /// Report on how a part is solved from an input returned by `parse`, empty by default
+    fn report(&self, _input: &dyn Any, _part: u8) -> Result<String, String> {
+        Ok(String::new())
+    }
+}
+
+/// Wraps a `Solution` into a `DynSolution`
+pub struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S> Registered<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Registered<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> DynSolution for Registered<S>
+where S: Solution, S::Input: 'static
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, InputError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String, String> {
+        let input: &S::Input = match input.downcast_ref::<S::Input>() {
+            Some(val) => val,
+            None => {
+                return Err("The input wasn't parsed by this day".to_string());
+            }
+        };
+        match part {
+            1 => S::part1(input),
+            2 => S::part2(input),
+            _ => Err(format!("Unknown part: {part}"))
+        }
+    }
+}
+
+/// Wraps a `Configurable` solution and its options into a `DynSolution`
+pub struct Configured<S: Configurable> {
+    options: S::Options
+}
+
+impl<S: Configurable> Configured<S> {
+    pub fn new(options: S::Options) -> Self {
+        Self { options }
+    }
+}
+
+impl<S> DynSolution for Configured<S>
+where S: Configurable, S::Input: 'static, S::Options: Sync
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, InputError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8) -> Result<String, String> {
+        let input: &S::Input = match input.downcast_ref::<S::Input>() {
+            Some(val) => val,
+            None => {
+                return Err("The input wasn't parsed by this day".to_string());
+            }
+        };
+        match part {
+            1 => S::part1_with(input, &self.options),
+            2 => S::part2_with(input, &self.options),
+            _ => Err(format!("Unknown part: {part}"))
+        }
+    }
+
+    fn report(&self, input: &dyn Any, part: u8) -> Result<String, String> {
+        let input: &S::Input = match input.downcast_ref::<S::Input>() {
+            Some(val) => val,
+            None => {
+                return Err("The input wasn't parsed by this day".to_string());
+            }
+        };
+        S::get_report(input, &self.options, part)
+    }
+}
+
+/// Solve the given parts of a day, `day_dir` being the directory holding the `files` of the day.
+/// The input is only parsed again when the parts don't read the same file.
+pub fn solve_parts(solution: &dyn DynSolution, source: &InputSource, day_dir: &Path, parts: &[u8]) -> Result<Vec<(u8, String)>, String> {
+    __solve_parts(solution, source, day_dir, parts, None)
+}
+
+/// `solve_parts`, the reports of the parts being added to `reports` if set
+fn __solve_parts(solution: &dyn DynSolution, source: &InputSource, day_dir: &Path, parts: &[u8], mut reports: Option<&mut Vec<String>>) -> Result<Vec<(u8, String)>, String> {
+    let mut results: Vec<(u8, String)> = Vec::new();
+    let mut parsed: Option<(Option<PathBuf>, Box<dyn Any>)> = None;
+    for part in parts {
+        let path: Option<PathBuf> = source.get_path(day_dir, *part);
+        let input: &dyn Any = match parsed {
+            Some((ref parsed_path, ref input)) if *parsed_path == path => input.as_ref(),
+            _ => {
+                let raw_input = source.load(day_dir, *part)?;
+                let input = match solution.parse(raw_input.content()) {
+                    Ok(val) => val,
+                    Err(error) => {
+                        return Err(match raw_input.path() {
+                            Some(raw_path) => error.with_path(raw_path),
+                            None => error
+                        }.to_string());
+                    }
+                };
+                parsed.insert((path, input)).1.as_ref()
+            }
+        };
+        match solution.solve(input, *part) {
+            Ok(result) => {
+                results.push((*part, result));
+            }
+            Err(error) => {
+                return Err(format!("Error while solving part {part}: {error}"));
+            }
+        }
+        if let Some(reports) = reports.as_mut() {
+            match solution.report(input, *part) {
+                Ok(report) => {
+                    reports.push(report);
+                }
+                Err(error) => {
+                    return Err(format!("Error while reporting on part {part}: {error}"));
+                }
+            }
+        }
+    }
+    Ok(results)
+}
+
+/// Entry point of the day binaries: solve both parts from the input selected on the command line
+pub fn run_main<S>(day_dir: &str) -> Result<(), i8>
+where S: Solution, S::Input: 'static
+{
+    __run_main(day_dir, "", |others| {
+        if others.is_empty() {
+            Ok(Registered::<S>::new())
+        }
+        else {
+            Err(format!("Unexpected arguments: {:?}", others))
+        }
+    })
+}
+
+/// Entry point of the day binaries taking their own options along with the input options
+pub fn run_main_with_options<S>(day_dir: &str) -> Result<(), i8>
+where S: Configurable, S::Input: 'static, S::Options: Sync
+{
+    __run_main(day_dir, S::OPTIONS, |others| Ok(Configured::<S>::new(S::parse_options(others)?)))
+}
+
+fn __run_main<D, F>(day_dir: &str, day_options: &str, configure: F) -> Result<(), i8>
+where D: DynSolution, F: FnOnce(&[String]) -> Result<D, String>
+{
+    let usage = if day_options.is_empty() { INPUT_OPTIONS.to_string() } else { format!("{INPUT_OPTIONS}\n\n{day_options}") };
+    let (source, solution): (InputSource, D) = match InputSource::from_args(env::args().skip(1)) {
+        Ok((_, others)) if others.iter().any(|arg| arg == "--help") => {
+            println!("{usage}");
+            return Ok(());
+        }
+        Ok((source, others)) => {
+            match configure(&others) {
+                Ok(solution) => (source, solution),
+                Err(error) => {
+                    eprintln!("{error}\n\n{usage}");
+                    return Err(-1);
+                }
+            }
+        }
+        Err(error) => {
+            eprintln!("{error}\n\n{usage}");
+            return Err(-1);
+        }
+    };
+
+    let mut reports: Vec<String> = Vec::new();
+    match __solve_parts(&solution, &source, Path::new(day_dir), &PARTS, Some(&mut reports)) {
+        Ok(results) => {
+            for (part, result) in results {
+                println!("Part {part}: {result}");
+            }
+            for report in reports {
+                eprint!("{report}");
+            }
+            Ok(())
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            Err(-1)
+        }
+    }
+}
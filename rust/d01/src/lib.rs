@@ -0,0 +1,317 @@
+use std::{fmt, path::Path};
+
+use aho_corasick::AhoCorasick;
+use aoc_input::{Input, InputError};
+use aoc_solution::{Configurable, Solution};
+
+/// Day 1: Trebuchet?!
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Lines of the calibration document
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        Ok(Input::new(input).lines().into_iter().map(|line| line.to_owned()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &Day01Options::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &Day01Options::default())
+    }
+}
+
+impl Configurable for Day01 {
+    type Options = Day01Options;
+
+    const OPTIONS: &'static str = "Day 1 options:
+    --lexicon <path>    File of the spelled digits of part 2, one '<word> <value>' per line, '#'
+                        starting a comment (default: 'zero' to 'nine'). Digits always count
+    --mode <mode>       'lenient' (default) skips the blank lines, 'strict' fails on them and reports
+                        the first and last token of every line of part 2. Both fail on a line
+                        without digits";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        let mut options = Day01Options::default();
+        let mut args_iter = args.iter();
+        while let Some(option) = args_iter.next() {
+            let value = match args_iter.next() {
+                Some(val) => val,
+                None => {
+                    return Err(format!("Missing value after {option}"));
+                }
+            };
+            match option.as_str() {
+                "--lexicon" => {
+                    options.lexicon = Lexicon::load(Path::new(value))?;
+                }
+                "--mode" => {
+                    options.mode = match value.as_str() {
+                        "lenient" => CalibrationMode::Lenient,
+                        "strict" => CalibrationMode::Strict,
+                        _ => {
+                            return Err(format!("Unknown mode: '{value}'"));
+                        }
+                    };
+                }
+                _ => {
+                    return Err(format!("Unexpected argument: '{option}'"));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Sum the calibration values of the input file
+    fn part1_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        sum_calibration_values(input, &DigitMatcher::new(&Lexicon::empty())?, options.mode)
+    }
+
+    /// Sum the calibration values of the input file, spelled digits included
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        sum_calibration_values(input, &DigitMatcher::new(&options.lexicon)?, options.mode)
+    }
+
+    /// The first and last tokens of every line of part 2, in strict mode
+    fn get_report(input: &Self::Input, options: &Self::Options, part: u8) -> Result<String, String> {
+        let mut res = String::new();
+        if part != 2 || options.mode != CalibrationMode::Strict {
+            return Ok(res);
+        }
+        for calibration in get_calibrations(input, &DigitMatcher::new(&options.lexicon)?, options.mode)? {
+            res += &format!("{calibration}\n");
+        }
+        Ok(res)
+    }
+}
+
+/// The options of the day 1 binary
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Day01Options {
+    /// The spelled digits of part 2
+    pub lexicon: Lexicon,
+    pub mode: CalibrationMode
+}
+
+/// How the lines of the calibration document are checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CalibrationMode {
+    /// Blank lines are skipped
+    #[default]
+    Lenient,
+    /// Every line needs a digit, and the tokens found on each line are reported
+    Strict
+}
+
+/// Words standing for a digit (or any value, e.g. 'ten'), on top of the digits themselves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lexicon {
+    words: Vec<(String, u32)>
+}
+
+/// The english words of the puzzle statement, and 'zero'
+impl Default for Lexicon {
+    fn default() -> Self {
+        Self::from_words([("zero", 0), ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9)])
+    }
+}
+
+impl Lexicon {
+    /// Only the digits
+    pub fn empty() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    pub fn from_words<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self { words: words.into_iter().map(|(word, value)| (word.to_string(), value)).collect() }
+    }
+
+    /// Parses one '<word> <value>' per line, '#' starting a comment
+    pub fn parse(input: &Input) -> Result<Self, InputError> {
+        let mut words = Vec::<(String, u32)>::new();
+        for (index_line, line) in input.lines().into_iter().enumerate() {
+            let line = match line.split_once('#') {
+                Some((before, _)) => before,
+                None => line
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                [word, value] => {
+                    let value: u32 = match value.parse::<u32>() {
+                        Ok(val) => val,
+                        Err(error) => {
+                            return Err(input.error(index_line + 1, format!("Invalid value '{value}' for '{word}': {error}")));
+                        }
+                    };
+                    if words.iter().any(|(other, _)| other == word) {
+                        return Err(input.error(index_line + 1, format!("The word '{word}' is listed twice")));
+                    }
+                    words.push((word.to_string(), value));
+                }
+                _ => {
+                    return Err(input.error(index_line + 1, "Expected '<word> <value>'"));
+                }
+            }
+        }
+        Ok(Self { words })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match Input::read(path).and_then(|input| Self::parse(&input)) {
+            Ok(val) => Ok(val),
+            Err(error) => Err(format!("Error while loading the lexicon: {error}"))
+        }
+    }
+}
+
+/// A digit, or a word of the lexicon, found in a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    /// Byte offsets of the token in the line, end excluded
+    pub start: usize,
+    pub end: usize,
+    pub value: u32
+}
+
+/// Finds the digits and the words of a lexicon in a single pass, with an Aho-Corasick automaton
+pub struct DigitMatcher {
+    automaton: AhoCorasick,
+    /// Value of each pattern of the automaton
+    values: Vec<u32>
+}
+
+impl DigitMatcher {
+    pub fn new(lexicon: &Lexicon) -> Result<Self, String> {
+        let mut patterns: Vec<String> = (0..10).map(|digit: u32| digit.to_string()).collect();
+        let mut values: Vec<u32> = (0..10).collect();
+        for (word, value) in &lexicon.words {
+            patterns.push(word.clone());
+            values.push(*value);
+        }
+        match AhoCorasick::new(&patterns) {
+            Ok(automaton) => Ok(Self { automaton, values }),
+            Err(error) => Err(format!("Error while building the digit matcher: {error}"))
+        }
+    }
+
+    /// Returns every token of the line, overlapping ones included ('eightwo' gives 8 and 2), by start offset
+    pub fn find_tokens(&self, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = self.automaton.find_overlapping_iter(line)
+            .map(|found| Token { start: found.start(), end: found.end(), value: self.values[found.pattern().as_usize()] })
+            .collect();
+        tokens.sort_by_key(|token| (token.start, token.end));
+        tokens
+    }
+}
+
+/// The first and last tokens of a line of the calibration document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineCalibration {
+    /// From 1
+    pub line_nb: usize,
+    pub first: Token,
+    pub first_text: String,
+    pub last: Token,
+    pub last_text: String
+}
+
+impl LineCalibration {
+    /// Returns None if the line has no token
+    fn new(line_nb: usize, line: &str, matcher: &DigitMatcher) -> Option<Self> {
+        let tokens = matcher.find_tokens(line);
+        let (first, last) = (*tokens.first()?, *tokens.last()?);
+        Some(Self {
+            line_nb,
+            first,
+            first_text: line[first.start..first.end].to_string(),
+            last,
+            last_text: line[last.start..last.end].to_string()
+        })
+    }
+
+    pub fn get_value(&self) -> u64 {
+        10 * u64::from(self.first.value) + u64::from(self.last.value)
+    }
+}
+
+impl fmt::Display for LineCalibration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: first '{}' at byte {}, last '{}' at byte {} -> {}",
+            self.line_nb, self.first_text, self.first.start, self.last_text, self.last.start, self.get_value())
+    }
+}
+
+/// Returns the first and last tokens of every line, the blank ones being skipped in lenient mode
+pub fn get_calibrations(input_lines: &[String], matcher: &DigitMatcher, mode: CalibrationMode) -> Result<Vec<LineCalibration>, String> {
+    let mut calibrations = Vec::<LineCalibration>::with_capacity(input_lines.len());
+    for (index_line, line) in input_lines.iter().enumerate() {
+        if mode == CalibrationMode::Lenient && line.trim().is_empty() {
+            continue;
+        }
+        match LineCalibration::new(index_line + 1, line, matcher) {
+            Some(calibration) => {
+                calibrations.push(calibration);
+            }
+            None => {
+                return Err(format!("line {}: No digit found in '{line}'", index_line + 1));
+            }
+        }
+    }
+    Ok(calibrations)
+}
+
+/// Sum the calibration values (first and last digits) of every line
+fn sum_calibration_values(input_lines: &[String], matcher: &DigitMatcher, mode: CalibrationMode) -> Result<String, String> {
+    let calibrations = get_calibrations(input_lines, matcher, mode)?;
+    let result: u64 = calibrations.iter().map(|calibration| calibration.get_value()).sum();
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_values(matcher: &DigitMatcher, line: &str) -> Vec<u32> {
+        matcher.find_tokens(line).iter().map(|token| token.value).collect()
+    }
+
+    #[test]
+    fn overlapping_words_are_all_found() {
+        let matcher = DigitMatcher::new(&Lexicon::default()).unwrap();
+        assert_eq!(get_values(&matcher, "eightwo"), vec![8, 2]);
+        assert_eq!(get_values(&matcher, "xtwone3four"), vec![2, 1, 3, 4]);
+        assert_eq!(matcher.find_tokens("a1b")[0], Token { start: 1, end: 2, value: 1 });
+        assert_eq!(get_values(&DigitMatcher::new(&Lexicon::empty()).unwrap(), "eightwo7"), vec![7]);
+        assert_eq!(get_values(&matcher, "zerone"), vec![0, 1]);
+    }
+
+    #[test]
+    fn lexicon_is_loaded_at_runtime() {
+        let lexicon = Lexicon::parse(&Input::new("# french\nun 1\ndeux 2\ndix 10\n\nzero 0 # alias\n")).unwrap();
+        let matcher = DigitMatcher::new(&lexicon).unwrap();
+        assert_eq!(get_values(&matcher, "dixzerodeux"), vec![10, 0, 2]);
+        assert_eq!(sum_calibration_values(&["dix3un".to_string()], &matcher, CalibrationMode::Lenient), Ok("101".to_string()));
+        assert!(Lexicon::parse(&Input::new("un 1\nun 2\n")).is_err());
+        assert!(Lexicon::parse(&Input::new("un\n")).is_err());
+    }
+
+    #[test]
+    fn lines_without_digits_are_reported() {
+        let matcher = DigitMatcher::new(&Lexicon::default()).unwrap();
+        let lines: Vec<String> = ["two1nine", "", "abc"].iter().map(|line| line.to_string()).collect();
+        assert_eq!(sum_calibration_values(&lines[..2], &matcher, CalibrationMode::Lenient), Ok("29".to_string()));
+        assert_eq!(sum_calibration_values(&lines[..2], &matcher, CalibrationMode::Strict), Err("line 2: No digit found in ''".to_string()));
+        assert_eq!(sum_calibration_values(&lines, &matcher, CalibrationMode::Lenient), Err("line 3: No digit found in 'abc'".to_string()));
+
+        let calibrations = get_calibrations(&["xtwone3four".to_string(), "7".to_string()], &matcher, CalibrationMode::Strict).unwrap();
+        assert_eq!(calibrations[0].to_string(), "line 1: first 'two' at byte 1, last 'four' at byte 7 -> 24");
+
+        let options = Day01Options { mode: CalibrationMode::Strict, ..Day01Options::default() };
+        assert_eq!(Day01::get_report(&lines[..1].to_vec(), &options, 1), Ok(String::new()));
+        assert_eq!(Day01::get_report(&lines[..1].to_vec(), &options, 2), Ok("line 1: first 'two' at byte 0, last 'nine' at byte 4 -> 29\n".to_string()));
+    }
+}
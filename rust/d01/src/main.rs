@@ -0,0 +1,5 @@
+use d01::Day01;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day01>(env!("CARGO_MANIFEST_DIR"))
+}
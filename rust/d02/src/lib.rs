@@ -0,0 +1,264 @@
+use std::{collections::BTreeMap, fmt};
+
+use aoc_input::{Input, InputError};
+use aoc_solution::{Configurable, Solution};
+
+/// Day 2: Cube Conundrum
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &Bag::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &Bag::default())
+    }
+}
+
+impl Configurable for Day02 {
+    /// The bag of the first part
+    type Options = Bag;
+
+    const OPTIONS: &'static str = "Day 2 options:
+    --bag <cubes>    Content of the bag of the first part, e.g. '12 red, 13 green, 14 blue' (default)";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        match args {
+            [] => Ok(Bag::default()),
+            [option, cubes] if option == "--bag" => Bag::parse(cubes),
+            _ => Err(format!("Unexpected arguments: {:?}", args))
+        }
+    }
+
+    /// Sum the ids of the games that are possible with the bag content
+    fn part1_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        let mut result: u32 = 0;
+        for game in input {
+            if game.is_possible_with(options) {
+                result += game.id;
+            }
+        }
+        Ok(result.to_string())
+    }
+
+    /// Sum the power of the minimal set of cubes of every game, over the colours of the bag and of the game
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        let mut result: u64 = 0;
+        for game in input {
+            result += game.get_minimal_bag(options).get_power();
+        }
+        Ok(result.to_string())
+    }
+}
+
+/// Amount of cubes of each colour, in a bag or in a draw
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>
+}
+
+/// The bag of the puzzle statement
+impl Default for Bag {
+    fn default() -> Self {
+        Self::from_cubes([("red", 12), ("green", 13), ("blue", 14)])
+    }
+}
+
+impl Bag {
+    /// A bag without any cube
+    pub fn new() -> Self {
+        Self { cubes: BTreeMap::new() }
+    }
+
+    pub fn from_cubes<'a>(cubes: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self { cubes: cubes.into_iter().map(|(colour, amount)| (colour.to_string(), amount)).collect() }
+    }
+
+    /// Parses cubes written like in the puzzle input, e.g. '3 blue, 4 red'
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut res = Self::new();
+        for cubes in text.split(',') {
+            let (amount, colour) = match cubes.trim().split_once(' ') {
+                Some(val) => val,
+                None => {
+                    return Err(format!("Expected '<amount> <colour>', found '{}'", cubes.trim()));
+                }
+            };
+            let amount: u32 = match amount.parse::<u32>() {
+                Ok(val) => val,
+                Err(error) => {
+                    return Err(format!("Error while getting the quantity of {} cubes: {error}", colour.trim()));
+                }
+            };
+            let colour = colour.trim();
+            if colour.is_empty() || colour.contains(char::is_whitespace) {
+                return Err(format!("Invalid colour: '{colour}'"));
+            }
+            if res.cubes.insert(colour.to_string(), amount).is_some() {
+                return Err(format!("The {colour} cubes are listed twice in '{}'", text.trim()));
+            }
+        }
+        Ok(res)
+    }
+
+    /// Amount of cubes of the colour, 0 if the bag has none
+    pub fn get(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(|colour| colour.as_str())
+    }
+
+    /// Returns true if the cubes of other could all have been taken out of the bag
+    pub fn contains(&self, other: &Bag) -> bool {
+        other.cubes.iter().all(|(colour, amount)| *amount <= self.get(colour))
+    }
+
+    /// Returns the smallest bag containing both bags
+    pub fn union(&self, other: &Bag) -> Bag {
+        let mut res = self.clone();
+        for (colour, amount) in &other.cubes {
+            let res_amount = res.cubes.entry(colour.clone()).or_insert(0);
+            *res_amount = (*res_amount).max(*amount);
+        }
+        res
+    }
+
+    /// Product of the amounts of every colour of the bag
+    pub fn get_power(&self) -> u64 {
+        self.cubes.values().map(|amount| *amount as u64).product()
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cubes: Vec<String> = self.cubes.iter().map(|(colour, amount)| format!("{amount} {colour}")).collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+/// Represents a Game, and every draw of cubes shown
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    draws: Vec<Bag>
+}
+
+impl Game {
+    fn new(id: u32, draws: Vec<Bag>) -> Game {
+        Self {
+            id,
+            draws
+        }
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn get_draws(&self) -> &[Bag] {
+        &self.draws
+    }
+
+    /// Returns true if every draw could have been taken out of the bag
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|draw| bag.contains(draw))
+    }
+
+    /// Returns the smallest bag the game could have been played with, the colours of the reference bag
+    /// that are never drawn having 0 cubes
+    pub fn get_minimal_bag(&self, reference: &Bag) -> Bag {
+        let bag = Bag::from_cubes(reference.colours().map(|colour| (colour, 0)));
+        self.draws.iter().fold(bag, |bag, draw| bag.union(draw))
+    }
+}
+
+/// Process a single line into a Game struct
+fn get_line_data(line: &str) -> Result<Game, String> {
+    let (header, draws_text) = match line.split_once(':') {
+        Some(val) => val,
+        None => {
+            return Err(format!("Missing ':' after the game id in '{line}'"));
+        }
+    };
+    let id: u32 = match header.strip_prefix("Game").map(|id| id.trim().parse::<u32>()) {
+        Some(Ok(val)) => val,
+        Some(Err(error)) => {
+            return Err(format!("Error while parsing a line to get the Game id: {error}"));
+        }
+        None => {
+            return Err(format!("A line starts with 'Game <id>:', found '{header}'"));
+        }
+    };
+
+    let mut draws: Vec<Bag> = Vec::new();
+    for draw_text in draws_text.split(';') {
+        match Bag::parse(draw_text) {
+            Ok(draw) => {
+                draws.push(draw);
+            }
+            Err(error) => {
+                return Err(format!("Error while parsing a draw of game {id}: {error}"));
+            }
+        }
+    }
+    Ok(Game::new(id, draws))
+}
+
+/// Return the input file as vec of Games
+fn get_input(input: &Input) -> Result<Vec<Game>, InputError> {
+    let mut result: Vec<Game> = Vec::new();
+    for (index_line, line) in input.lines().into_iter().enumerate() {
+        match get_line_data(line) {
+            Ok(game) => {
+                result.push(game);
+            }
+            Err(error) => {
+                return Err(input.error(index_line + 1, error));
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_draw_is_kept() {
+        let games = Day02::parse("Game 7: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n").unwrap();
+        assert_eq!(games[0].get_id(), 7);
+        assert_eq!(games[0].get_draws().len(), 3);
+        assert_eq!(games[0].get_draws()[1], Bag::from_cubes([("red", 1), ("green", 2), ("blue", 6)]));
+        assert_eq!(games[0].get_minimal_bag(&Bag::default()).to_string(), "6 blue, 2 green, 4 red");
+        assert_eq!(games[0].get_minimal_bag(&Bag::default()).get_power(), 48);
+    }
+
+    #[test]
+    fn colours_never_drawn_count_as_zero() {
+        let games = Day02::parse("Game 1: 3 red, 2 green; 1 red\nGame 2: 2 purple\n").unwrap();
+        assert_eq!(games[0].get_minimal_bag(&Bag::default()).to_string(), "0 blue, 2 green, 3 red");
+        assert_eq!(Day02::part2(&games), Ok("0".to_string()));
+        assert_eq!(games[1].get_minimal_bag(&Bag::parse("1 purple").unwrap()).get_power(), 2);
+        assert_eq!(Game::new(3, Vec::new()).get_minimal_bag(&Bag::default()).get_power(), 0);
+    }
+
+    #[test]
+    fn any_colour_and_any_bag() {
+        let games = Day02::parse("Game 1: 2 purple, 1 red; 3 purple\nGame 2: 4 purple\n").unwrap();
+        let bag = Bag::parse("3 purple, 1 red").unwrap();
+        let possible: Vec<u32> = games.iter().filter(|game| game.is_possible_with(&bag)).map(|game| game.get_id()).collect();
+        assert_eq!(possible, vec![1]);
+        assert!(Bag::parse("3 red, 2 red").is_err());
+        assert!(Day02::parse("Game 1: 3 red, blue\n").is_err());
+    }
+}
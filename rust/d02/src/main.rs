@@ -0,0 +1,5 @@
+use d02::Day02;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day02>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,82 @@
+use std::fmt::Write;
+
+use ansi_term::{Colour, Style};
+
+use aoc_grid::Grid;
+
+use super::{get_product, HasCoordinates, Schematic, Surface};
+
+/// Returns the JSON string of the char, escaped if needed
+fn to_json_string(huchar: char) -> String {
+    match huchar {
+        '"' => "\"\\\"\"".to_string(),
+        '\\' => "\"\\\\\"".to_string(),
+        _ if huchar.is_control() => format!("\"\\u{:04x}\"", huchar as u32),
+        _ => format!("\"{huchar}\"")
+    }
+}
+
+/// The inclusive ranges of the surface
+fn surface_to_json(surface: &Surface) -> String {
+    let (x_range, y_range) = (surface.get_x_range(), surface.get_y_range());
+    format!("{{\"x_range\": [{}, {}], \"y_range\": [{}, {}]}}", x_range.start, x_range.end, y_range.start, y_range.end)
+}
+
+fn indexes_to_json(indexes: &[usize]) -> String {
+    let indexes: Vec<String> = indexes.iter().map(|index| index.to_string()).collect();
+    format!("[{}]", indexes.join(", "))
+}
+
+/// Returns the schematic as JSON: the numbers and symbols with their surfaces, the index of their adjacent
+/// symbols/numbers, and the gear ratio of the gears ('*' adjacent to exactly 2 numbers, null otherwise)
+pub fn to_json(schematic: &Schematic) -> String {
+    let mut res = String::new();
+    let _ = writeln!(res, "{{\n  \"width\": {},\n  \"height\": {},\n  \"numbers\": [", schematic.grid.width(), schematic.grid.height());
+    for (index_number, pn) in schematic.part_numbers.iter().enumerate() {
+        let symbols = &schematic.number_symbols[index_number];
+        let _ = write!(res, "    {{\"id\": {index_number}, \"value\": {}, \"x\": {}, \"y\": {}, \"length\": {}, \"surface\": {}, \"symbols\": {}, \"valid\": {}}}",
+            pn.value, pn.char_x, pn.char_y, pn.nb_len, surface_to_json(&pn.get_surface()), indexes_to_json(symbols), !symbols.is_empty());
+        res += if index_number + 1 < schematic.part_numbers.len() { ",\n" } else { "\n" };
+    }
+
+    res += "  ],\n  \"symbols\": [\n";
+    for (index_symbol, symbol) in schematic.symbols.iter().enumerate() {
+        let gear_ratio = match schematic.get_gear_numbers(index_symbol) {
+            Some(numbers) => get_product(&numbers).to_string(),
+            None => "null".to_string()
+        };
+        let _ = write!(res, "    {{\"id\": {index_symbol}, \"symbol\": {}, \"x\": {}, \"y\": {}, \"surface\": {}, \"numbers\": {}, \"gear_ratio\": {gear_ratio}}}",
+            to_json_string(symbol.value), symbol.char_x, symbol.char_y, surface_to_json(&symbol.get_surface()),
+            indexes_to_json(&schematic.symbol_numbers[index_symbol]));
+        res += if index_symbol + 1 < schematic.symbols.len() { ",\n" } else { "\n" };
+    }
+    res += "  ]\n}\n";
+    res
+}
+
+/// Returns the schematic with the valid part numbers in green, the rejected ones in red, the gears in purple
+/// and the other symbols in yellow
+pub fn render(schematic: &Schematic) -> String {
+    let mut styles: Grid<Style> = schematic.grid.map(|_| Style::new());
+    for (pn, symbols) in schematic.part_numbers.iter().zip(&schematic.number_symbols) {
+        let colour = if symbols.is_empty() { Colour::Red } else { Colour::Green };
+        for x in pn.char_x..pn.char_x + pn.nb_len {
+            styles[(x, pn.char_y)] = colour.normal();
+        }
+    }
+    for (index_symbol, symbol) in schematic.symbols.iter().enumerate() {
+        styles[(symbol.char_x, symbol.char_y)] = match schematic.get_gear_numbers(index_symbol) {
+            Some(_) => Colour::Purple.bold(),
+            None => Colour::Yellow.normal()
+        };
+    }
+
+    let mut res = String::with_capacity(3 * (schematic.grid.width() + 1) * schematic.grid.height());
+    for (row, row_styles) in schematic.grid.rows().zip(styles.rows()) {
+        for (huchar, style) in row.iter().zip(row_styles) {
+            res += &style.paint(huchar.to_string()).to_string();
+        }
+        res += "\n";
+    }
+    res
+}
@@ -0,0 +1,521 @@
+use std::{fmt, path::PathBuf};
+
+use aoc_grid::Grid;
+use aoc_input::{Input, InputError};
+use aoc_solution::{Configurable, Solution};
+
+mod export;
+
+/// Day 3: Gear Ratios
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input), AdjacencyMethod::default())
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &Day03Options::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &Day03Options::default())
+    }
+}
+
+impl Configurable for Day03 {
+    type Options = Day03Options;
+
+    const OPTIONS: &'static str = "Day 3 options, used by part 2:
+    --json <path>    Write the schematic to a JSON file: numbers, symbols, their surfaces,
+                     which ones are adjacent, and the gear ratios
+    --render         Print the schematic, the valid part numbers in green, the rejected ones
+                     in red, the gears in purple and the other symbols in yellow";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        let mut options = Day03Options::default();
+        let mut args_iter = args.iter();
+        while let Some(option) = args_iter.next() {
+            match option.as_str() {
+                "--json" => {
+                    options.json = match args_iter.next() {
+                        Some(path) => Some(PathBuf::from(path)),
+                        None => {
+                            return Err("Missing path after --json".to_string());
+                        }
+                    };
+                }
+                "--render" => {
+                    options.render = true;
+                }
+                _ => {
+                    return Err(format!("Unexpected argument: '{option}'"));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Sum the part numbers adjacent to at least one symbol
+    fn part1_with(input: &Self::Input, _options: &Self::Options) -> Result<String, String> {
+        let result: u32 = input.get_numbers_adjacent_to(|_| true).iter().sum();
+        Ok(result.to_string())
+    }
+
+    /// Sum the gear ratios of the gears adjacent to exactly two part numbers
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        if let Some(path) = &options.json {
+            if let Err(error) = std::fs::write(path, export::to_json(input)) {
+                return Err(format!("Error while writing {}: {error}", path.display()));
+            }
+        }
+        let result: u64 = input.get_symbols_with_numbers(|symbol| symbol == '*', 2).iter().map(|(_, numbers)| get_product(numbers)).sum();
+        Ok(result.to_string())
+    }
+
+    /// The coloured schematic, if asked for
+    fn get_report(input: &Self::Input, options: &Self::Options, part: u8) -> Result<String, String> {
+        if part == 2 && options.render {
+            Ok(export::render(input))
+        }
+        else {
+            Ok(String::new())
+        }
+    }
+}
+
+/// The options of the day 3 binary
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Day03Options {
+    /// JSON file the schematic is written to, if set
+    pub json: Option<PathBuf>,
+    pub render: bool
+}
+
+/// The engine schematic: every number and every symbol found in it, and which ones are adjacent
+pub struct Schematic {
+    grid: Grid<char>,
+    part_numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    /// Indexes of the symbols adjacent to each number
+    number_symbols: Vec<Vec<usize>>,
+    /// Indexes of the numbers adjacent to each symbol
+    symbol_numbers: Vec<Vec<usize>>
+}
+
+/// How the numbers adjacent to each symbol are found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdjacencyMethod {
+    /// Only the numbers in the cells of a spatial index around the symbol are checked
+    #[default]
+    Index,
+    /// Every number is checked against every symbol
+    NestedLoop
+}
+
+impl Schematic {
+    /// Parse the schematic like `Day03::parse`, finding the adjacent numbers and symbols with the given method
+    pub fn parse_with(input: &str, method: AdjacencyMethod) -> Result<Self, InputError> {
+        get_input(&Input::new(input), method)
+    }
+
+    /// Builds the adjacency graph between the numbers and the symbols
+    fn new(grid: Grid<char>, part_numbers: Vec<PartNumber>, symbols: Vec<Symbol>, method: AdjacencyMethod) -> Self {
+        let mut number_symbols: Vec<Vec<usize>> = vec![Vec::new(); part_numbers.len()];
+        let mut symbol_numbers: Vec<Vec<usize>> = vec![Vec::new(); symbols.len()];
+        let index = match method {
+            AdjacencyMethod::Index => Some(SurfaceIndex::new(&part_numbers)),
+            AdjacencyMethod::NestedLoop => None
+        };
+
+        for (index_symbol, symbol) in symbols.iter().enumerate() {
+            let surface = symbol.get_surface();
+            let candidates: Vec<usize> = match &index {
+                Some(index) => index.query(&surface),
+                None => (0..part_numbers.len()).collect()
+            };
+            for index_number in candidates {
+                if surface.surface_intersect(&part_numbers[index_number].get_surface()) {
+                    number_symbols[index_number].push(index_symbol);
+                    symbol_numbers[index_symbol].push(index_number);
+                }
+            }
+        }
+        // Same order whatever the method
+        for numbers in symbol_numbers.iter_mut() {
+            numbers.sort_unstable();
+        }
+        Self { grid, part_numbers, symbols, number_symbols, symbol_numbers }
+    }
+
+    /// Returns the value of the numbers adjacent to at least one symbol of the set
+    pub fn get_numbers_adjacent_to(&self, is_in_set: impl Fn(char) -> bool) -> Vec<u32> {
+        let mut res = Vec::<u32>::new();
+        for (pn, symbols) in self.part_numbers.iter().zip(&self.number_symbols) {
+            if symbols.iter().any(|index_symbol| is_in_set(self.symbols[*index_symbol].value)) {
+                res.push(pn.value);
+            }
+        }
+        res
+    }
+
+    /// Returns the value of the 2 numbers adjacent to the symbol if it is a gear
+    fn get_gear_numbers(&self, index_symbol: usize) -> Option<Vec<u32>> {
+        let numbers = &self.symbol_numbers[index_symbol];
+        if self.symbols[index_symbol].value != '*' || numbers.len() != 2 {
+            return None;
+        }
+        Some(numbers.iter().map(|index_number| self.part_numbers[*index_number].value).collect())
+    }
+
+    /// Returns the symbols of the set adjacent to exactly `nb_numbers` numbers, with the value of these numbers
+    pub fn get_symbols_with_numbers(&self, is_in_set: impl Fn(char) -> bool, nb_numbers: usize) -> Vec<(char, Vec<u32>)> {
+        let mut res = Vec::<(char, Vec<u32>)>::new();
+        for (symbol, numbers) in self.symbols.iter().zip(&self.symbol_numbers) {
+            if is_in_set(symbol.value) && numbers.len() == nb_numbers {
+                res.push((symbol.value, numbers.iter().map(|index_number| self.part_numbers[*index_number].value).collect()));
+            }
+        }
+        res
+    }
+}
+
+/// Product of the numbers, e.g. the gear ratio of the numbers adjacent to a gear
+pub fn get_product(numbers: &[u32]) -> u64 {
+    numbers.iter().map(|nb| *nb as u64).product()
+}
+
+#[derive(Debug)]
+struct InclusiveRange {
+    start: usize,
+    end: usize
+}
+
+impl InclusiveRange {
+    fn range_insersect(&self, other: &Self) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+}
+
+struct Surface {
+    x: usize,
+    y: usize,
+    widht: usize,
+    height: usize
+}
+
+impl Surface {
+    fn new(x: usize, y: usize, widht: usize, height: usize) -> Surface {
+        Surface {
+            x,
+            y,
+            widht,
+            height
+        }
+    }
+    ///  Returns a range for the x axis of the Surface
+    fn get_x_range(&self) -> InclusiveRange {
+        InclusiveRange {start: self.x, end: self.x + self.widht}
+    }
+    ///  Returns a range for the y axis of the Surface
+    fn get_y_range(&self) -> InclusiveRange {
+        InclusiveRange {start: self.y, end: self.y + self.height}
+    }
+    /// Returns true if self and other intersects, false otherwise
+    fn surface_intersect(&self, other: &Self) -> bool {
+        if self.get_x_range().range_insersect(&other.get_x_range())
+        && self.get_y_range().range_insersect(&other.get_y_range()) {
+            return true;
+        }
+        false
+    }
+}
+
+impl fmt::Display for Surface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Surface: x_range:{:?} | y_range:{:?}", self.get_x_range(), self.get_y_range())
+    }
+}
+
+/// Side of the square cells of the SurfaceIndex
+const CELL_SIZE: usize = 8;
+
+/// Grid-hashed spatial index: the plane is cut in square cells, each one listing the items whose surface covers it
+struct SurfaceIndex {
+    nb_columns: usize,
+    nb_rows: usize,
+    cells: Vec<Vec<usize>>
+}
+
+impl SurfaceIndex {
+    fn new<T: HasCoordinates>(items: &[T]) -> Self {
+        let surfaces: Vec<Surface> = items.iter().map(|item| item.get_surface()).collect();
+        let nb_columns = surfaces.iter().map(|surface| surface.get_x_range().end / CELL_SIZE + 1).max().unwrap_or(0);
+        let nb_rows = surfaces.iter().map(|surface| surface.get_y_range().end / CELL_SIZE + 1).max().unwrap_or(0);
+
+        let mut res = Self { nb_columns, nb_rows, cells: vec![Vec::new(); nb_columns * nb_rows] };
+        for (index_item, surface) in surfaces.iter().enumerate() {
+            for cell in res.get_cells(surface) {
+                res.cells[cell].push(index_item);
+            }
+        }
+        res
+    }
+
+    /// Returns the index of the cells covered by the surface, the ones outside of the index excluded
+    fn get_cells(&self, surface: &Surface) -> Vec<usize> {
+        let (x_range, y_range) = (surface.get_x_range(), surface.get_y_range());
+        let mut res = Vec::<usize>::new();
+        for cell_y in y_range.start / CELL_SIZE..(y_range.end / CELL_SIZE + 1).min(self.nb_rows) {
+            for cell_x in x_range.start / CELL_SIZE..(x_range.end / CELL_SIZE + 1).min(self.nb_columns) {
+                res.push(cell_y * self.nb_columns + cell_x);
+            }
+        }
+        res
+    }
+
+    /// Returns the items sharing a cell with the surface, sorted: the only ones that can intersect it
+    fn query(&self, surface: &Surface) -> Vec<usize> {
+        let mut res: Vec<usize> = self.get_cells(surface).into_iter().flat_map(|cell| self.cells[cell].iter().copied()).collect();
+        res.sort_unstable();
+        res.dedup();
+        res
+    }
+}
+
+trait HasCoordinates {
+    fn get_x(&self) -> usize;
+    fn get_y(&self) -> usize;
+    fn get_widht(&self) -> usize;
+    fn get_height(&self) -> usize;
+    fn get_surface(&self) -> Surface;
+}
+
+
+
+/// # Represents all number from the input
+/// * The char_x and char_y coordinates are pointing from top to bot and left to right
+/// * char_x and char_y are coordinates of the first numeric char
+#[derive(Debug, Clone)]
+struct PartNumber {
+    value: u32,
+    nb_len: usize,
+    char_x: usize,
+    char_y: usize
+}
+
+impl PartNumber {
+    fn new(value: u32, nb_len: usize, char_x: usize, char_y: usize) -> PartNumber {
+        PartNumber {
+            value,
+            nb_len,
+            char_x,
+            char_y
+        }
+    }
+}
+
+impl HasCoordinates for PartNumber {
+    /// char_x: x coord of the char
+    fn get_x(&self) -> usize {
+        self.char_x
+    }
+    /// char_y: y coord of the char
+    fn get_y(&self) -> usize {
+        self.char_y
+    }
+    /// PartNumber has a fixed height of 1
+    fn get_height(&self) -> usize {
+        0
+    }
+    /// Lenght of the number
+    fn get_widht(&self) -> usize {
+        self.nb_len - 1
+    }
+    /// Returns a Surface that defines the PartNumber area, can then be used to check if intersection between 2 Surfaces
+    fn get_surface(&self) -> Surface {
+        Surface::new(self.get_x(), self.get_y(), self.get_widht(), self.get_height())
+    }
+}
+
+impl fmt::Display for PartNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PartNumber value:{}, char_x:{}, char_y:{}, Surface:{}", self.value, self.char_x, self.char_y, self.get_surface())
+    }
+}
+
+/// # Represents a symbol, '*' being a gear
+/// * char_x and char_y are coordinates of the symbol
+#[derive(Debug, Clone)]
+struct Symbol {
+    value: char,
+    char_x: usize,
+    char_y: usize
+}
+
+impl Symbol {
+    fn new(value: char, char_x: usize, char_y: usize) -> Symbol {
+        Symbol {
+            value,
+            char_x,
+            char_y
+        }
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Symbol '{}' char_x: {}, char_y: {}, Surface: {}", self.value, self.char_x, self.char_y, self.get_surface())
+    }
+}
+
+impl HasCoordinates for Symbol {
+
+    /// char_x - 1 because the surface of the Symbol contains its surrounding
+    fn get_x(&self) -> usize {
+        self.char_x.saturating_sub(1)
+    }
+    /// char_y - 1 because the surface of the Symbol contains its surrounding
+    fn get_y(&self) -> usize {
+        self.char_y.saturating_sub(1)
+    }
+    /// Symbol has a height of 3 (bot + symbol + top), less on the first row
+    fn get_height(&self) -> usize {
+        self.char_y + 1 - self.get_y()
+    }
+    /// Symbol has a width of 3 (left + symbol + right), less on the first column
+    fn get_widht(&self) -> usize {
+        self.char_x + 1 - self.get_x()
+    }
+    /// Returns a Surface that defines the Symbol area, can then be used to check if intersection between 2 Surfaces
+    fn get_surface(&self) -> Surface {
+        Surface::new(self.get_x(), self.get_y(), self.get_widht(), self.get_height())
+    }
+}
+
+
+fn get_input(input: &Input, method: AdjacencyMethod) -> Result<Schematic, InputError> {
+    let mut vec_partnumber: Vec<PartNumber> = Vec::new();
+    let mut vec_symbol: Vec<Symbol> = Vec::new();
+
+    let grid: Grid<char> = Grid::parse(input)?;
+    if grid.height() == 0 {
+        return Err(input.error(1, "The schematic is empty"));
+    }
+
+    for (index_line, line) in grid.rows().enumerate() {
+        let mut index_char: usize = 0;
+        while index_char < line.len() {
+            if !line[index_char].is_ascii_digit() {
+                if is_symbol(line[index_char]) {
+                    vec_symbol.push(Symbol::new(line[index_char], index_char, index_line));
+                }
+                index_char += 1;
+                continue;
+            }
+
+            // Read the whole number
+            let first_char: usize = index_char;
+            let mut number: u32 = 0;
+            while let Some(val) = line.get(index_char).and_then(|huchar| huchar.to_digit(10)) {
+                number = match number.checked_mul(10).and_then(|number| number.checked_add(val)) {
+                    Some(number) => number,
+                    None => {
+                        return Err(input.error(index_line + 1, format!("The number starting at column {} is too large", first_char + 1)));
+                    }
+                };
+                index_char += 1;
+            }
+            vec_partnumber.push(PartNumber::new(number, index_char - first_char, first_char, index_line));
+        }
+    }
+    Ok(Schematic::new(grid, vec_partnumber, vec_symbol, method))
+}
+
+/// Anything that is neither a digit nor a '.' is a symbol
+fn is_symbol(huchar: char) -> bool {
+    huchar != '.' && !huchar.is_ascii_digit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacency_queries() {
+        let schematic = Day03::parse(include_str!("../files/input_debug.txt")).unwrap();
+        // 114 and 58 aren't adjacent to any symbol
+        assert_eq!(schematic.get_numbers_adjacent_to(|_| true).len(), schematic.part_numbers.len() - 2);
+        assert_eq!(schematic.get_numbers_adjacent_to(|symbol| symbol == '#'), vec![633]);
+
+        let gears = schematic.get_symbols_with_numbers(|symbol| symbol == '*', 2);
+        assert_eq!(gears, vec![('*', vec![467, 35]), ('*', vec![755, 598])]);
+        assert_eq!(schematic.get_symbols_with_numbers(|symbol| symbol == '*', 1), vec![('*', vec![617])]);
+    }
+
+    #[test]
+    fn too_large_numbers_are_errors() {
+        let error = Day03::parse("12*.........\n.99999999999\n").err().unwrap();
+        assert_eq!(error.to_string(), "line 2: The number starting at column 2 is too large");
+    }
+
+    #[test]
+    fn json_export_links_gears_to_their_numbers() {
+        let schematic = Day03::parse("467..\n...*.\n..35.\n....\"\n").unwrap();
+        let json = export::to_json(&schematic);
+        assert!(json.contains("{\"id\": 0, \"symbol\": \"*\", \"x\": 3, \"y\": 1, \"surface\": {\"x_range\": [2, 4], \"y_range\": [0, 2]}, \"numbers\": [0, 1], \"gear_ratio\": 16345}"), "{json}");
+        assert!(json.contains("\"symbol\": \"\\\"\""), "{json}");
+        assert!(json.contains("\"valid\": true"));
+    }
+
+    /// Random schematic whose numbers have 1 to 3 digits, like the real ones
+    fn generate_schematic(size: usize, mut seed: u64) -> String {
+        let mut res = String::with_capacity((size + 1) * size);
+        for _ in 0..size {
+            let mut nb_digits: usize = 0;
+            for _ in 0..size {
+                // Linear congruential generator of Knuth's MMIX
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                let huchar = match (seed >> 33) % 30 {
+                    0..=9 if nb_digits < 3 => char::from_digit(((seed >> 33) % 10) as u32, 10).unwrap_or('0'),
+                    10..=12 => ['*', '#', '+'][((seed >> 33) % 3) as usize],
+                    _ => '.'
+                };
+                nb_digits = if huchar.is_ascii_digit() { nb_digits + 1 } else { 0 };
+                res.push(huchar);
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    #[test]
+    fn index_finds_the_same_numbers_as_the_nested_loop() {
+        for content in [include_str!("../files/input_debug.txt"), include_str!("../files/input.txt"), &generate_schematic(50, 3)] {
+            let (indexed, nested) = (Schematic::parse_with(content, AdjacencyMethod::Index).unwrap(), Schematic::parse_with(content, AdjacencyMethod::NestedLoop).unwrap());
+            assert_eq!(indexed.symbol_numbers, nested.symbol_numbers);
+            assert_eq!(indexed.number_symbols, nested.number_symbols);
+        }
+    }
+
+    /// Slow because of the nested loop: cargo test -p d03 -- --ignored
+    #[test]
+    #[ignore]
+    fn index_is_faster_than_the_nested_loop() {
+        for size in [100, 200, 400] {
+            let content = generate_schematic(size, size as u64);
+            let mut timings = Vec::<std::time::Duration>::new();
+            for method in [AdjacencyMethod::Index, AdjacencyMethod::NestedLoop] {
+                let schematic = Day03::parse(&content).unwrap();
+                let start = std::time::Instant::now();
+                let schematic = Schematic::new(schematic.grid, schematic.part_numbers, schematic.symbols, method);
+                timings.push(start.elapsed());
+                assert!(schematic.part_numbers.iter().all(|pn| (1..=3).contains(&pn.nb_len)));
+                assert!(!schematic.symbol_numbers.is_empty());
+            }
+            assert!(timings[0] < timings[1], "{size}x{size}: index {:.2?}, nested loop {:.2?}", timings[0], timings[1]);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use d03::Day03;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day03>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,323 @@
+use std::{cmp::min, fmt, hash::Hash, ops::Range};
+
+use aoc_input::{parse_numbers, Input, InputError};
+use aoc_solution::{Configurable, Solution};
+use hashbrown::HashSet;
+
+/// Day 4: Scratchcards
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &Day04Options::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &Day04Options::default())
+    }
+}
+
+impl Configurable for Day04 {
+    type Options = Day04Options;
+
+    const OPTIONS: &'static str = "Day 4 options:
+    --trace    Print how the copies won by each card propagate to the following cards
+    --stats    Print statistics on the won copies: the card with the most copies, and the
+               card winning the most copies";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        let mut options = Day04Options::default();
+        for option in args {
+            match option.as_str() {
+                "--trace" => {
+                    options.trace = true;
+                }
+                "--stats" => {
+                    options.stats = true;
+                }
+                _ => {
+                    return Err(format!("Unexpected argument: '{option}'"));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Sum the scores of every scratchcard
+    fn part1_with(input: &Self::Input, _options: &Self::Options) -> Result<String, String> {
+        Ok(sum_scores(input).to_string())
+    }
+
+    /// Count the total amount of scratchcards once every won copy has been processed
+    fn part2_with(input: &Self::Input, _options: &Self::Options) -> Result<String, String> {
+        Ok(count_cards(input).to_string())
+    }
+
+    /// The cascade of the copies and their statistics, if asked for
+    fn get_report(input: &Self::Input, options: &Self::Options, part: u8) -> Result<String, String> {
+        let mut res = String::new();
+        if part != 2 {
+            return Ok(res);
+        }
+        if options.trace {
+            for step in get_cascade(input) {
+                res += &format!("{step}\n");
+            }
+        }
+        if options.stats {
+            res += &format!("{}\n", get_copy_stats(input));
+        }
+        Ok(res)
+    }
+}
+
+/// The options of the day 4 binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Day04Options {
+    pub trace: bool,
+    pub stats: bool
+}
+
+/// Parses the cards, every card having as many winning numbers and numbers as the first one
+fn get_input(input: &Input) -> Result<Vec<Card>, InputError> {
+    let mut cards: Vec<Card> = Vec::new();
+    // (winning numbers, numbers) of the first card
+    let mut widths: Option<(usize, usize)> = None;
+
+    for (index_line, line) in input.lines().into_iter().enumerate() {
+        let (header, numbers) = match line.split_once(':') {
+            Some(val) => val,
+            None => {
+                return Err(input.error(index_line + 1, format!("Missing ':' after the card id in '{line}'")));
+            }
+        };
+        let id: usize = match header.strip_prefix("Card").map(|id| id.trim().parse::<usize>()) {
+            Some(Ok(val)) => val,
+            Some(Err(error)) => {
+                return Err(input.error(index_line + 1, format!("Error while parsing the card id. Error: {error}")));
+            }
+            None => {
+                return Err(input.error(index_line + 1, format!("A card starts with 'Card <id>:', found '{header}'")));
+            }
+        };
+        // Copies are won for the following cards, which need to be in order
+        if id != cards.len() + 1 {
+            return Err(input.error(index_line + 1, format!("Expected card {}, found card {id}", cards.len() + 1)));
+        }
+
+        let (winning_part, card_part) = match numbers.split_once('|') {
+            Some(val) => val,
+            None => {
+                return Err(input.error(index_line + 1, format!("Missing '|' between the winning numbers and the numbers of card {id}")));
+            }
+        };
+        let (winning_nbs, card_nbs): (Vec<u8>, Vec<u8>) = match (parse_numbers(winning_part), parse_numbers(card_part)) {
+            (Ok(winning_nbs), Ok(card_nbs)) => (winning_nbs, card_nbs),
+            (Err(error), _) | (_, Err(error)) => {
+                return Err(input.error(index_line + 1, format!("Error while parsing the numbers for id: {id}. Error: {error}")));
+            }
+        };
+
+        match widths {
+            None => {
+                widths = Some((winning_nbs.len(), card_nbs.len()));
+            }
+            Some((nb_winnings, nb_cards)) if (winning_nbs.len(), card_nbs.len()) != (nb_winnings, nb_cards) => {
+                return Err(input.error(index_line + 1, format!("Card {id} has {} winning numbers and {} numbers, the first card has {nb_winnings} and {nb_cards}",
+                    winning_nbs.len(), card_nbs.len())));
+            }
+            Some(_) => {}
+        }
+        cards.push(Card::new(id, winning_nbs, card_nbs));
+    }
+    Ok(cards)
+}
+
+fn sum_scores(cards: &[Card]) -> usize {
+    cards.iter().map(|card| card.get_score()).sum()
+}
+
+fn count_cards(cards: &[Card]) -> usize {
+    get_copies(cards).iter().sum()
+}
+
+/// Returns the indexes of the cards won by the card at the given index
+fn get_won_range(cards: &[Card], index_card: usize) -> Range<usize> {
+    index_card + 1..min::<usize>(index_card + 1 + cards[index_card].get_amount_matches(), cards.len())
+}
+
+/// Returns how many copies of each card are owned once every won copy has been processed, the original included
+pub fn get_copies(cards: &[Card]) -> Vec<usize> {
+    // Every card is owned once, then the copies of a card are added to the following cards: added where the
+    // won range starts and removed where it ends, so the running sum gives the copies won by each card
+    let mut differences: Vec<isize> = vec![0; cards.len() + 1];
+    let mut copies: Vec<usize> = Vec::with_capacity(cards.len());
+    let mut won: isize = 0;
+    for index_card in 0..cards.len() {
+        won += differences[index_card];
+        let amount = 1 + won as usize;
+        copies.push(amount);
+
+        let won_range = get_won_range(cards, index_card);
+        differences[won_range.start] += amount as isize;
+        differences[won_range.end] -= amount as isize;
+    }
+    copies
+}
+
+/// How the copies of a card propagate to the following cards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CascadeStep {
+    pub id: usize,
+    /// Copies of the card, each one winning a copy of every card of `won`
+    pub copies: usize,
+    pub matches: usize,
+    /// Ids of the cards won
+    pub won: Range<usize>
+}
+
+impl fmt::Display for CascadeStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Card {} (x{}): {} matches", self.id, self.copies, self.matches)?;
+        if !self.won.is_empty() {
+            write!(f, ", +{} to cards {}..={}", self.copies, self.won.start, self.won.end - 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns how the copies propagated, card by card
+pub fn get_cascade(cards: &[Card]) -> Vec<CascadeStep> {
+    let copies = get_copies(cards);
+    let mut res = Vec::<CascadeStep>::with_capacity(cards.len());
+    for (index_card, card) in cards.iter().enumerate() {
+        let won_range = get_won_range(cards, index_card);
+        res.push(CascadeStep {
+            id: card.id,
+            copies: copies[index_card],
+            matches: card.get_amount_matches(),
+            // Ids are the indexes + 1, checked while parsing
+            won: won_range.start + 1..won_range.end + 1
+        });
+    }
+    res
+}
+
+/// Statistics on the won copies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyStats {
+    pub total: usize,
+    /// (id, copies) of the card with the most copies, the first one on ties
+    pub max_copies: (usize, usize),
+    /// (id, copies won) of the card making the most copies of the following cards, the first one on ties
+    pub most_influential: (usize, usize)
+}
+
+impl fmt::Display for CopyStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} cards, card {} has the most copies ({}), card {} won the most copies ({})",
+            self.total, self.max_copies.0, self.max_copies.1, self.most_influential.0, self.most_influential.1)
+    }
+}
+
+pub fn get_copy_stats(cards: &[Card]) -> CopyStats {
+    let mut stats = CopyStats { total: 0, max_copies: (0, 0), most_influential: (0, 0) };
+    for step in get_cascade(cards) {
+        stats.total += step.copies;
+        if step.copies > stats.max_copies.1 {
+            stats.max_copies = (step.id, step.copies);
+        }
+        let won = step.copies * step.won.len();
+        if won > stats.most_influential.1 {
+            stats.most_influential = (step.id, won);
+        }
+    }
+    stats
+}
+
+pub fn get_intersection<T>(nums: [Vec<T>; 2]) -> Vec<T>
+where T: Eq + PartialEq + Hash + Clone + Copy
+{
+    let mut intersect_result: Vec<T> = nums[0].clone();
+
+    for temp_vec in nums {
+        let unique_a: HashSet<T> = temp_vec.into_iter().collect();
+        intersect_result = unique_a
+            .intersection(&intersect_result.into_iter().collect()).copied()
+            .collect::<Vec<_>>();
+    }
+    intersect_result
+}
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    id: usize,
+    winning_nbs: Vec<u8>,
+    card_nbs: Vec<u8>
+}
+
+impl Card {
+    fn new(id: usize, winning_nbs: Vec<u8>, card_nbs: Vec<u8>) -> Self {
+        Self {
+            id,
+            winning_nbs,
+            card_nbs
+        }
+    }
+
+    fn get_amount_matches(&self) -> usize {
+        get_intersection([self.card_nbs.clone(), self.winning_nbs.clone()]).len()
+    }
+
+    fn get_score(&self) -> usize {
+        let mut score: usize = 0;
+        let amount_matches = self.get_amount_matches();
+        if amount_matches > 0 {
+            score = 1;
+            for _ in 1..amount_matches {
+                score <<= 1;
+            }
+        }
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widths_are_inferred_from_the_first_card() {
+        let cards = Day04::parse("Card 1: 1 2 | 2 3 1\nCard 2: 4 5 | 6 7 8\n").unwrap();
+        assert_eq!((cards[0].winning_nbs.len(), cards[0].card_nbs.len()), (2, 3));
+        assert_eq!(sum_scores(&cards), 2);
+        assert_eq!(count_cards(&cards), 3);
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let error = Day04::parse("Card 1: 1 2 | 2 3 1\nCard 2: 4 5 | 6 7\n").err().unwrap();
+        assert!(error.to_string().contains("line 2"), "{error}");
+        assert!(Day04::parse("Card 1: 1 2 | 2 3 1\nCard 3: 4 5 | 6 7 8\n").is_err());
+        assert!(Day04::parse("Card 1: 1 2 2 3 1\n").is_err());
+    }
+
+    #[test]
+    fn copies_cascade_like_the_example() {
+        let cards = Day04::parse(include_str!("../files/input_debug.txt")).unwrap();
+        assert_eq!(get_copies(&cards), vec![1, 2, 4, 8, 14, 1]);
+
+        let cascade = get_cascade(&cards);
+        assert_eq!(cascade[1].to_string(), "Card 2 (x2): 2 matches, +2 to cards 3..=4");
+        assert_eq!(cascade[5].to_string(), "Card 6 (x1): 0 matches");
+
+        let stats = get_copy_stats(&cards);
+        assert_eq!((stats.total, stats.max_copies, stats.most_influential), (30, (5, 14), (3, 8)));
+    }
+}
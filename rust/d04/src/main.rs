@@ -0,0 +1,5 @@
+use d04::Day04;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day04>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,388 @@
+use aoc_input::{parse_numbers, Input, InputError};
+use aoc_solution::Solution;
+
+/// Day 5: If You Give A Seed A Fertilizer
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input))
+    }
+
+    /// Returns the lowest location number that corresponds to any of the initial seeds
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        let mut location_result: u64 = 0xFFFFFFFFFFFFFFFF;
+        for seed in &input.seeds {
+            location_result = location_result.min(get_location(&input.entries, *seed));
+        }
+        Ok(location_result.to_string())
+    }
+
+    /// Returns the lowest location number that corresponds to any of the initial seed ranges
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        if !input.seeds.len().is_multiple_of(2) {
+            return Err("Seeds should come as pairs of start and lenght".to_string());
+        }
+        // Seed ranges of length 0 have no seed to plant
+        let seed_ranges: Vec<AlmanacRange> = input.seeds.chunks(2)
+            .map(|pair| AlmanacRange::new(pair[0], pair[0] + pair[1]))
+            .filter(|range| !range.is_empty())
+            .collect();
+
+        // Map whole ranges instead of every seed, the lowest location being the start of a location range
+        let location_ranges: Vec<AlmanacRange> = get_location_ranges(&input.entries, seed_ranges);
+        match location_ranges.iter().map(|range| range.start).min() {
+            Some(val) => Ok(val.to_string()),
+            None => Err("No seed range to plant".to_string())
+        }
+    }
+}
+
+/// The seeds to be planted, and every map of the almanac
+pub struct Almanac {
+    seeds: Vec<u64>,
+    entries: Vec<AlmanacEntry>
+}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+
+    /// The maps of the almanac, in the order of the input
+    pub fn entries(&self) -> &[AlmanacEntry] {
+        &self.entries
+    }
+}
+
+/// The categories of ids, `Unknown` and `LastTable` being the bounds of the chain of maps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlmanacDescr
+{
+    Unknown,
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+    LastTable
+}
+
+/// A range of ids, from `start` included to `end` excluded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlmanacRange
+{
+    start: u64,
+    end: u64
+}
+
+impl AlmanacRange
+{
+    pub fn new(start: u64, end: u64) -> AlmanacRange {
+        AlmanacRange {
+            start,
+            end
+        }
+    }
+
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        self.start <= id && id < self.end
+    }
+
+    /// Split the range at the bounds of `other`, returns the part within `other` and the parts outside of it
+    pub fn split(&self, other: &AlmanacRange) -> (Option<AlmanacRange>, Vec<AlmanacRange>) {
+        let inside = AlmanacRange::new(self.start.max(other.start), self.end.min(other.end));
+        let before = AlmanacRange::new(self.start, self.end.min(other.start));
+        let after = AlmanacRange::new(self.start.max(other.end), self.end);
+
+        let outside: Vec<AlmanacRange> = [before, after].into_iter().filter(|range| !range.is_empty()).collect();
+        if inside.is_empty() {
+            (None, outside)
+        }
+        else {
+            (Some(inside), outside)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AlmanacEntry
+{
+    desc_src: AlmanacDescr,
+    desc_dst: AlmanacDescr,
+    ranges_src: Vec<AlmanacRange>,
+    ranges_dst: Vec<AlmanacRange>
+}
+
+impl AlmanacEntry {
+    fn new(desc_src: AlmanacDescr, desc_dst: AlmanacDescr, ranges_src: Vec<AlmanacRange>, ranges_dst: Vec<AlmanacRange>) -> Self {
+        Self {
+            desc_src,
+            desc_dst,
+            ranges_src,
+            ranges_dst
+        }
+    }
+
+    /// Build the map from `desc_src` to `desc_dst` ids, from its `(dst start, src start, length)` lines
+    pub fn from_mappings(desc_src: AlmanacDescr, desc_dst: AlmanacDescr, mappings: &[(u64, u64, u64)]) -> Self {
+        let ranges_src: Vec<AlmanacRange> = mappings.iter().map(|(_, src_start, length)| AlmanacRange::new(*src_start, src_start + length)).collect();
+        let ranges_dst: Vec<AlmanacRange> = mappings.iter().map(|(dst_start, _, length)| AlmanacRange::new(*dst_start, dst_start + length)).collect();
+        Self::new(desc_src, desc_dst, ranges_src, ranges_dst)
+    }
+
+    pub fn desc_src(&self) -> AlmanacDescr {
+        self.desc_src
+    }
+
+    pub fn desc_dst(&self) -> AlmanacDescr {
+        self.desc_dst
+    }
+
+    fn get_dst(&self, src: u64) -> u64 {
+        for (i, alma_range_src) in self.ranges_src.iter().enumerate() {
+            if alma_range_src.contains(src) {
+                return self.ranges_dst[i].start + src - alma_range_src.start;
+            }
+        }
+        src
+    }
+
+    /// Map a range of source ids to the destination ranges, splitting it at the bounds of the entry mappings.
+    /// The ids outside of every mapping are kept as is
+    pub fn map_range(&self, range: &AlmanacRange) -> Vec<AlmanacRange> {
+        let mut mapped: Vec<AlmanacRange> = Vec::new();
+        let mut unmapped: Vec<AlmanacRange> = vec![*range];
+        for (alma_range_src, alma_range_dst) in self.ranges_src.iter().zip(&self.ranges_dst) {
+            let mut remaining: Vec<AlmanacRange> = Vec::new();
+            for current_range in unmapped {
+                let (inside, outside) = current_range.split(alma_range_src);
+                if let Some(inside) = inside {
+                    mapped.push(AlmanacRange::new(alma_range_dst.start + inside.start - alma_range_src.start,
+                                                  alma_range_dst.start + inside.end - alma_range_src.start));
+                }
+                remaining.extend(outside);
+            }
+            unmapped = remaining;
+        }
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+fn get_input(input: &Input) -> Result<Almanac, InputError>
+{
+    let almanac_descriptions: [(AlmanacDescr, &str); 8] = [ (AlmanacDescr::Seed, "seed"),
+                                                            (AlmanacDescr::Soil, "soil"),
+                                                            (AlmanacDescr::Fertilizer, "fertilizer"),
+                                                            (AlmanacDescr::Water, "water"),
+                                                            (AlmanacDescr::Light, "light"),
+                                                            (AlmanacDescr::Temperature, "temperature"),
+                                                            (AlmanacDescr::Humidity, "humidity"),
+                                                            (AlmanacDescr::Location, "location")];
+
+    let paragraphs = input.paragraphs();
+
+    // Get seeds, from the first block of the almanac
+    let (seeds_paragraph, map_paragraphs) = match paragraphs.split_first() {
+        Some(val) => val,
+        None => {
+            return Err(input.error(1, "The almanac is empty"));
+        }
+    };
+    let seeds_line: &str = match seeds_paragraph.lines[0].strip_prefix("seeds:") {
+        Some(val) => val,
+        None => {
+            return Err(input.error(seeds_paragraph.first_line, "Expected the 'seeds:' entry"));
+        }
+    };
+    let seeds: Vec<u64> = match parse_numbers(seeds_line) {
+        Ok(val) => val,
+        Err(error) => {
+            return Err(input.error(seeds_paragraph.first_line, format!("Error while parsing the seeds: {error}")));
+        }
+    };
+
+    // Every other block is an Almanac Entry
+    let mut almanac_entries: Vec<AlmanacEntry> = Vec::new();
+    for paragraph in map_paragraphs {
+        let to_parse_line: &str = match paragraph.lines[0].strip_suffix(" map:") {
+            Some(val) => val,
+            None => {
+                return Err(input.error(paragraph.first_line, "Expected a '<src>-to-<dst> map:' header"));
+            }
+        };
+        let mut desc_src: AlmanacDescr = AlmanacDescr::Unknown;
+        let mut desc_dst: AlmanacDescr = AlmanacDescr::Unknown;
+        for (i, almanac_map_desc_entry) in to_parse_line.split("-to-").enumerate() {
+            for almanac_desc in &almanac_descriptions {
+                if almanac_map_desc_entry == almanac_desc.1 {
+                    // Src descr
+                    if i % 2 == 0 {
+                        desc_src = almanac_desc.0;
+                    }
+                    // Dst descr
+                    else {
+                        desc_dst = almanac_desc.0;
+                    }
+                }
+            }
+        }
+
+        // Almanac entry mapping
+        let mut ranges_src: Vec<AlmanacRange> = Vec::new();
+        let mut ranges_dst: Vec<AlmanacRange> = Vec::new();
+        for (index_line, line) in paragraph.lines.iter().enumerate().skip(1) {
+            let line_nb = paragraph.first_line + index_line;
+            let (dst_start, src_start, lenght): (u64, u64, u64) = match parse_numbers::<u64>(line) {
+                Ok(nbs) if nbs.len() == 3 => (nbs[0], nbs[1], nbs[2]),
+                Ok(nbs) => {
+                    return Err(input.error(line_nb, format!("Expected dst start, src start and lenght, found {} numbers", nbs.len())));
+                }
+                Err(error) => {
+                    return Err(input.error(line_nb, format!("Error while parsing the mapping: {error}")));
+                }
+            };
+            ranges_src.push(AlmanacRange::new(src_start, src_start + lenght));
+            ranges_dst.push(AlmanacRange::new(dst_start, dst_start + lenght));
+        }
+        almanac_entries.push(AlmanacEntry::new(desc_src, desc_dst, ranges_src, ranges_dst));
+    }
+
+    Ok(Almanac { seeds, entries: almanac_entries })
+}
+
+fn get_next_alma_desc(current_alma_desc: AlmanacDescr) -> AlmanacDescr {
+    match current_alma_desc {
+        AlmanacDescr::Seed => {
+            AlmanacDescr::Soil
+        }
+        AlmanacDescr::Soil => {
+            AlmanacDescr::Fertilizer
+        }
+        AlmanacDescr::Fertilizer => {
+            AlmanacDescr::Water
+        }
+        AlmanacDescr::Water => {
+            AlmanacDescr::Light
+        }
+        AlmanacDescr::Light => {
+            AlmanacDescr::Temperature
+        }
+        AlmanacDescr::Temperature => {
+            AlmanacDescr::Humidity
+        }
+        AlmanacDescr::Humidity => {
+            AlmanacDescr::Location
+        }
+        AlmanacDescr::Location => {
+            AlmanacDescr::LastTable
+        }
+        AlmanacDescr::LastTable => {
+            AlmanacDescr::LastTable
+        }
+        AlmanacDescr::Unknown => {
+            AlmanacDescr::Unknown
+        }
+    }
+}
+
+fn get_alma_entry_index_from_desc(alma_entries: &[AlmanacEntry], alma_desc: AlmanacDescr) -> Option<usize> {
+    for (i, alma_entry) in alma_entries.iter().enumerate() {
+        if alma_entry.desc_src == alma_desc {
+            return Some(i);
+        }
+    }
+    None
+}
+
+
+/// Follow the almanac maps from a seed up to its location
+fn get_location(almanac_entries: &[AlmanacEntry], seed: u64) -> u64 {
+    let mut current_alma_id = seed;
+    let mut current_alma_desc = AlmanacDescr::Seed;
+    while current_alma_desc != AlmanacDescr::LastTable {
+        let alma_entry_index: usize = match get_alma_entry_index_from_desc(almanac_entries, current_alma_desc) {
+            Some(val) => val,
+            None => {
+                // eprintln!("No Almanac Entry found for src_desc: {:?}", current_alma_desc);
+                break;
+            }
+        };
+
+        current_alma_id = almanac_entries[alma_entry_index].get_dst(current_alma_id);
+        current_alma_desc = get_next_alma_desc(current_alma_desc);
+    }
+    current_alma_id
+}
+
+/// Follow the almanac maps from seed ranges up to the location ranges
+pub fn get_location_ranges(almanac_entries: &[AlmanacEntry], seed_ranges: Vec<AlmanacRange>) -> Vec<AlmanacRange> {
+    let mut current_ranges = seed_ranges;
+    let mut current_alma_desc = AlmanacDescr::Seed;
+    while current_alma_desc != AlmanacDescr::LastTable {
+        let alma_entry_index: usize = match get_alma_entry_index_from_desc(almanac_entries, current_alma_desc) {
+            Some(val) => val,
+            None => {
+                break;
+            }
+        };
+
+        current_ranges = current_ranges.iter().flat_map(|range| almanac_entries[alma_entry_index].map_range(range)).collect();
+        current_alma_desc = get_next_alma_desc(current_alma_desc);
+    }
+    current_ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_are_split_at_the_bounds() {
+        let range = AlmanacRange::new(10, 20);
+        assert_eq!(range.split(&AlmanacRange::new(12, 15)), (Some(AlmanacRange::new(12, 15)), vec![AlmanacRange::new(10, 12), AlmanacRange::new(15, 20)]));
+        assert_eq!(range.split(&AlmanacRange::new(0, 15)), (Some(AlmanacRange::new(10, 15)), vec![AlmanacRange::new(15, 20)]));
+        assert_eq!(range.split(&AlmanacRange::new(20, 30)), (None, vec![AlmanacRange::new(10, 20)]));
+    }
+
+    #[test]
+    fn ranges_are_mapped_like_every_id() {
+        // seed-to-soil map of the example: 50 98 2 / 52 50 48
+        let entry = AlmanacEntry::new(AlmanacDescr::Seed, AlmanacDescr::Soil,
+                                      vec![AlmanacRange::new(98, 100), AlmanacRange::new(50, 98)],
+                                      vec![AlmanacRange::new(50, 52), AlmanacRange::new(52, 100)]);
+        let seed_range = AlmanacRange::new(40, 105);
+        let mut mapped_ids: Vec<u64> = entry.map_range(&seed_range).iter().flat_map(|range| range.start..range.end).collect();
+        let mut expected_ids: Vec<u64> = (seed_range.start..seed_range.end).map(|id| entry.get_dst(id)).collect();
+        mapped_ids.sort();
+        expected_ids.sort();
+        assert_eq!(mapped_ids, expected_ids);
+    }
+
+    #[test]
+    fn empty_seed_ranges_are_ignored() {
+        // Seed 0 would be planted at location 22
+        let input = include_str!("../files/input_debug.txt").replacen("seeds: 79 14 55 13", "seeds: 79 14 0 0 55 13", 1);
+        let almanac = Day05::parse(&input).unwrap();
+        assert_eq!(Day05::part2(&almanac), Ok("46".to_string()));
+        assert_eq!(Day05::part1(&almanac), Ok("22".to_string()));
+    }
+}
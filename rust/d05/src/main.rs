@@ -0,0 +1,5 @@
+use d05::Day05;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main::<Day05>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,23 @@
+use aoc_solution::Solution;
+use d05::{get_location_ranges, AlmanacDescr, AlmanacEntry, AlmanacRange, Day05};
+
+#[test]
+fn entries_are_built_and_mapped_from_outside() {
+    // seed-to-soil map of the example
+    let entry = AlmanacEntry::from_mappings(AlmanacDescr::Seed, AlmanacDescr::Soil, &[(50, 98, 2), (52, 50, 48)]);
+    assert_eq!((entry.desc_src(), entry.desc_dst()), (AlmanacDescr::Seed, AlmanacDescr::Soil));
+    assert_eq!(entry.map_range(&AlmanacRange::new(45, 55)), vec![AlmanacRange::new(52, 57), AlmanacRange::new(45, 50)]);
+    assert_eq!(get_location_ranges(&[entry], vec![AlmanacRange::new(98, 101)]), vec![AlmanacRange::new(50, 52), AlmanacRange::new(100, 101)]);
+}
+
+#[test]
+fn location_ranges_of_the_example() {
+    let almanac = Day05::parse(include_str!("../files/input_debug.txt")).unwrap();
+    assert_eq!(almanac.seeds(), &[79, 14, 55, 13]);
+    assert_eq!(almanac.entries().len(), 7);
+
+    let seed_ranges: Vec<AlmanacRange> = almanac.seeds().chunks(2).map(|pair| AlmanacRange::new(pair[0], pair[0] + pair[1])).collect();
+    let location_ranges = get_location_ranges(almanac.entries(), seed_ranges);
+    assert_eq!(location_ranges.iter().map(|range| range.start()).min(), Some(46));
+    assert_eq!(location_ranges.iter().map(|range| range.end() - range.start()).sum::<u64>(), 27);
+}
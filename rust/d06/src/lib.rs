@@ -0,0 +1,245 @@
+use std::fmt::Display;
+
+use aoc_input::{parse_numbers, Input, InputError};
+use aoc_solution::{Configurable, Solution};
+
+const DEBUG: bool = false;
+
+/// Day 6: Wait For It
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<BoatRace>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &RaceSolver::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &RaceSolver::default())
+    }
+}
+
+impl Configurable for Day06 {
+    type Options = RaceSolver;
+
+    const OPTIONS: &'static str = "Day 6 options:
+    --solver <solver>    How the ways to win are counted: 'closed-form' (default), 'brute-force',
+                         or 'cross-check' failing when both don't agree";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        match args {
+            [] => Ok(RaceSolver::default()),
+            [option, solver] if option == "--solver" => {
+                match solver.as_str() {
+                    "closed-form" => Ok(RaceSolver::ClosedForm),
+                    "brute-force" => Ok(RaceSolver::BruteForce),
+                    "cross-check" => Ok(RaceSolver::CrossCheck),
+                    _ => Err(format!("Unknown solver: '{solver}'"))
+                }
+            }
+            _ => Err(format!("Unexpected arguments: {:?}", args))
+        }
+    }
+
+    /// Multiply together the number of ways to beat the record of each race
+    fn part1_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        let mut result: usize = 1;
+        for boat_race in input.iter() {
+            let nb_wins = count_ways_to_win(boat_race, *options)?;
+            debug_print(format!("Race {}: {nb_wins} ways to win ({:?})", boat_race.id, boat_race));
+            if nb_wins > 0 {
+                result *= nb_wins;
+            }
+        }
+
+        Ok(result.to_string())
+    }
+
+    /// Count the number of ways to beat the record of the single long race
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        let boat_race: BoatRace = get_kerned_race(input)?;
+
+        let result: usize = count_ways_to_win(&boat_race, *options)?;
+        debug_print(format!("Race {}: {result} ways to win ({:?})", boat_race.id, boat_race));
+
+        Ok(result.to_string())
+    }
+}
+
+/// How the number of ways to win a race is computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RaceSolver {
+    /// Try every charging time
+    BruteForce,
+    /// Count the integers between the roots of `t * (time - t) = record_distance`
+    #[default]
+    ClosedForm,
+    /// Run both, and fail if they don't agree
+    CrossCheck
+}
+
+fn debug_print<T: Display>(arg: T) {
+    if DEBUG {
+        println!("ARO DEBUG: {arg}");
+    }
+}
+
+#[derive(Debug)]
+pub struct BoatRace {
+    id: usize,
+    time: usize,
+    record_distance: usize
+}
+
+impl BoatRace {
+    fn new(id: usize, time: usize, record_distance: usize) -> Self {
+        Self {
+            id,
+            time,
+            record_distance
+        }
+    }
+}
+
+
+fn get_input(input: &Input) -> Result<Vec<BoatRace>, InputError> {
+
+    let mut time_values: Vec<usize> = Vec::new();
+    let mut distance_values: Vec<usize> = Vec::new();
+    for (index_line, line) in input.lines().into_iter().enumerate() {
+        if let Some(times) = line.strip_prefix("Time:") {
+            time_values = match parse_numbers(times) {
+                Ok(val) => val,
+                Err(error) => {
+                    return Err(input.error(index_line + 1, format!("Error while parsing the time values: {error}")));
+                }
+            };
+        }
+        else if let Some(distances) = line.strip_prefix("Distance:") {
+            distance_values = match parse_numbers(distances) {
+                Ok(val) => val,
+                Err(error) => {
+                    return Err(input.error(index_line + 1, format!("Error while parsing the distance values: {error}")));
+                }
+            };
+        }
+    }
+
+    let mut boat_vec: Vec<BoatRace> = Vec::new();
+    for (id, (time, distance)) in time_values.iter().zip(distance_values.iter()).enumerate() {
+        boat_vec.push(BoatRace::new(id, *time, *distance));
+    }
+
+    Ok(boat_vec)
+}
+
+
+/// The spaces between the numbers of the sheet are just bad kerning: there is only one race
+fn get_kerned_race(boat_races: &[BoatRace]) -> Result<BoatRace, String> {
+    let time_str: String = boat_races.iter().map(|boat_race| boat_race.time.to_string()).collect();
+    let time: usize = match time_str.parse::<usize>() {
+        Ok(val) => val,
+        Err(error) => {
+            return Err(format!("Error while parsing the time value: '{time_str}'. Error: '{error}'"));
+        }
+    };
+    let distance_str: String = boat_races.iter().map(|boat_race| boat_race.record_distance.to_string()).collect();
+    let distance: usize = match distance_str.parse::<usize>() {
+        Ok(val) => val,
+        Err(error) => {
+            return Err(format!("Error while parsing the distance value: '{distance_str}'. Error: '{error}'"));
+        }
+    };
+    Ok(BoatRace::new(0, time, distance))
+}
+
+
+/// Returns the number of charging times beating the record of the race
+pub fn count_ways_to_win(boat_race: &BoatRace, solver: RaceSolver) -> Result<usize, String> {
+    match solver {
+        RaceSolver::BruteForce => Ok(process_boat_race(boat_race)),
+        RaceSolver::ClosedForm => process_boat_race_closed_form(boat_race),
+        RaceSolver::CrossCheck => {
+            let brute_force = process_boat_race(boat_race);
+            let closed_form = process_boat_race_closed_form(boat_race)?;
+            if brute_force != closed_form {
+                return Err(format!("Race {}: the brute force found {brute_force} ways to win, the closed form {closed_form} ({:?})", boat_race.id, boat_race));
+            }
+            Ok(closed_form)
+        }
+    }
+}
+
+fn process_boat_race(boat_race: &BoatRace) -> usize {
+    let mut nb_wins: usize = 0;
+    for charging_time in 0..boat_race.time {
+        let traveled_distance = (boat_race.time - charging_time) * charging_time;
+        if traveled_distance > boat_race.record_distance {
+            nb_wins += 1;
+        }
+    }
+    nb_wins
+}
+
+/// The traveled distance `t * (time - t)` beats the record between the roots of `t² - time * t + record_distance`,
+/// everything is computed on u128 so that `time²` can't overflow
+fn process_boat_race_closed_form(boat_race: &BoatRace) -> Result<usize, String> {
+    let time = boat_race.time as u128;
+    let record_distance = boat_race.record_distance as u128;
+    let beats_record = |charging_time: u128| charging_time * (time - charging_time) > record_distance;
+
+    // No real root: the record can't even be reached
+    let Some(discriminant) = (time * time).checked_sub(4 * record_distance) else {
+        return Ok(0);
+    };
+
+    // isqrt rounds down, so this is at most the first winning time: move forward past the exact ties
+    let mut first_win: u128 = (time - discriminant.isqrt()) / 2;
+    while first_win <= time / 2 && !beats_record(first_win) {
+        first_win += 1;
+    }
+    if first_win > time / 2 {
+        return Ok(0);
+    }
+
+    // The distances are symmetric around time / 2
+    let last_win: u128 = time - first_win;
+    match usize::try_from(last_win - first_win + 1) {
+        Ok(val) => Ok(val),
+        Err(error) => Err(format!("Too many ways to win: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_form_matches_the_brute_force() {
+        for time in 0..60 {
+            for record_distance in 0..(time * time / 4 + 2) {
+                let boat_race = BoatRace::new(0, time, record_distance);
+                assert_eq!(count_ways_to_win(&boat_race, RaceSolver::CrossCheck), Ok(process_boat_race(&boat_race)), "{:?}", boat_race);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_ties_do_not_win() {
+        // Charging 10 or 20ms travels exactly 200mm
+        assert_eq!(count_ways_to_win(&BoatRace::new(0, 30, 200), RaceSolver::ClosedForm), Ok(9));
+        // The only way to reach 9mm is charging 3ms
+        assert_eq!(count_ways_to_win(&BoatRace::new(0, 6, 9), RaceSolver::ClosedForm), Ok(0));
+    }
+
+    #[test]
+    fn long_races_do_not_overflow() {
+        let boat_race = BoatRace::new(0, u64::MAX as usize, 0);
+        assert_eq!(count_ways_to_win(&boat_race, RaceSolver::ClosedForm), Ok(u64::MAX as usize - 1));
+    }
+}
@@ -0,0 +1,5 @@
+use d06::Day06;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day06>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,500 @@
+use std::cmp::Ordering;
+
+use aoc_input::{Input, InputError};
+use aoc_solution::{Configurable, Solution};
+
+/// Day 7: Camel Cards
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &Day07Options::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &Day07Options::default())
+    }
+}
+
+impl Configurable for Day07 {
+    type Options = Day07Options;
+
+    const OPTIONS: &'static str = "Day 7 options, any of the rule options making both parts use the same rule set:
+    --rules <preset>          Rule set to start from: 'standard' (default) or 'jokers'
+    --order <cards>           The 13 cards from the strongest to the weakest, e.g. 'AKQT98765432J'
+    --wild <cards>            Cards standing for any other card, e.g. 'J' or 'J2' ('-' for none)
+    --tie-break <policy>      How hands of the same type are ordered: 'card-order' (default, the
+                              first different card) or 'poker' (the strongest groups of cards)
+    --explain                 Print every hand with its type, the cards the wild ones stand for,
+                              its rank, bid and winnings
+    --sort <key>              Order of the explained hands: 'rank' (default), 'line', 'bid',
+                              or 'winnings', implies --explain
+    --csv                     Print the explained hands as CSV, implies --explain";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        let mut options = Day07Options::default();
+        let (mut preset, mut order, mut wild, mut tie_break) = (None, None, None, None);
+        let mut args_iter = args.iter();
+        while let Some(option) = args_iter.next() {
+            match option.as_str() {
+                "--explain" => {
+                    options.explain.get_or_insert_with(Explain::default);
+                    continue;
+                }
+                "--csv" => {
+                    options.explain.get_or_insert_with(Explain::default).csv = true;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let value = match args_iter.next() {
+                Some(val) => val.as_str(),
+                None => {
+                    return Err(format!("Missing value after {option}"));
+                }
+            };
+            match option.as_str() {
+                "--rules" => {
+                    preset = match value {
+                        "standard" => Some(RuleSet::standard()),
+                        "jokers" => Some(RuleSet::jokers()),
+                        _ => {
+                            return Err(format!("Unknown rule set: '{value}'"));
+                        }
+                    };
+                }
+                "--order" => {
+                    order = Some(value.to_string());
+                }
+                "--wild" => {
+                    wild = Some(if value == "-" { String::new() } else { value.to_string() });
+                }
+                "--tie-break" => {
+                    tie_break = match value {
+                        "card-order" => Some(TieBreak::CardOrder),
+                        "poker" => Some(TieBreak::Poker),
+                        _ => {
+                            return Err(format!("Unknown tie-break policy: '{value}'"));
+                        }
+                    };
+                }
+                "--sort" => {
+                    options.explain.get_or_insert_with(Explain::default).sort = match value {
+                        "rank" => ExplainSort::Rank,
+                        "line" => ExplainSort::Line,
+                        "bid" => ExplainSort::Bid,
+                        "winnings" => ExplainSort::Winnings,
+                        _ => {
+                            return Err(format!("Unknown sort key: '{value}'"));
+                        }
+                    };
+                }
+                _ => {
+                    return Err(format!("Unexpected argument: '{option}'"));
+                }
+            }
+        }
+
+        if preset.is_some() || order.is_some() || wild.is_some() || tie_break.is_some() {
+            let preset = preset.unwrap_or_else(RuleSet::standard);
+            let order: String = order.unwrap_or_else(|| preset.order.iter().collect());
+            let wild: String = wild.unwrap_or_else(|| preset.wild.iter().collect());
+            options.rules = Some(RuleSet::new(&order, &wild, tie_break.unwrap_or(preset.tie_break))?);
+        }
+        Ok(options)
+    }
+
+    /// Sum the winnings (bid * rank) of every hand
+    fn part1_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        Ok(get_total_winnings(input, &options.get_rules(1)).to_string())
+    }
+
+    /// Sum the winnings (bid * rank) of every hand, J cards being jokers
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        Ok(get_total_winnings(input, &options.get_rules(2)).to_string())
+    }
+
+    /// How every hand was ranked, if asked for
+    fn get_report(input: &Self::Input, options: &Self::Options, part: u8) -> Result<String, String> {
+        match &options.explain {
+            Some(explain) => Ok(explain.get_report(part, &get_hand_reports(input, &options.get_rules(part)))),
+            None => Ok(String::new())
+        }
+    }
+}
+
+/// The options of the day 7 binary
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Day07Options {
+    /// The rule set used by both parts instead of their own, if set
+    pub rules: Option<RuleSet>,
+    /// Report how every hand was ranked, if set
+    pub explain: Option<Explain>
+}
+
+impl Day07Options {
+    /// The rule set of the part: the one of the options, else the one of the puzzle statement
+    fn get_rules(&self, part: u8) -> RuleSet {
+        match &self.rules {
+            Some(rules) => rules.clone(),
+            None if part == 1 => RuleSet::standard(),
+            None => RuleSet::jokers()
+        }
+    }
+}
+
+/// Order of the hands in the explanation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplainSort {
+    /// The weakest hand first
+    #[default]
+    Rank,
+    /// The order of the input
+    Line,
+    /// The highest bid first
+    Bid,
+    /// The highest winnings first
+    Winnings
+}
+
+/// How the ranking of the hands is printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Explain {
+    pub sort: ExplainSort,
+    /// CSV instead of an aligned table
+    pub csv: bool
+}
+
+impl Explain {
+    /// Returns the report of the hands of the part, sorted and formatted as asked
+    fn get_report(&self, part: u8, reports: &[HandReport]) -> String {
+        let mut reports: Vec<&HandReport> = reports.iter().collect();
+        match self.sort {
+            ExplainSort::Rank => reports.sort_by_key(|report| report.rank),
+            ExplainSort::Line => reports.sort_by_key(|report| report.line),
+            ExplainSort::Bid => reports.sort_by_key(|report| std::cmp::Reverse(report.bid)),
+            ExplainSort::Winnings => reports.sort_by_key(|report| std::cmp::Reverse(report.get_winnings()))
+        }
+
+        let mut res = String::new();
+        if self.csv {
+            res += "part,rank,line,hand,combo_type,played_as,bid,winnings\n";
+            for report in reports {
+                res += &format!("{part},{},{},{},{:?},{},{},{}\n", report.rank, report.line, report.cards, report.combo_type,
+                    report.played_as, report.bid, report.get_winnings());
+            }
+        }
+        else {
+            res += &format!("Part {part} hands:\n{:>6} {:>6}  {:<5}  {:<12}  {:<9}  {:>6} {:>10}\n", "rank", "line", "hand", "combo type", "played as", "bid", "winnings");
+            for report in reports {
+                res += &format!("{:>6} {:>6}  {:<5}  {:<12}  {:<9}  {:>6} {:>10}\n", report.rank, report.line, report.cards,
+                    format!("{:?}", report.combo_type), report.played_as, report.bid, report.get_winnings());
+            }
+        }
+        res
+    }
+}
+
+/// How a hand was ranked
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct HandReport {
+    /// Line of the hand in the input, from 1
+    line: usize,
+    cards: String,
+    combo_type: ComboType,
+    /// The cards once the wild ones are replaced by the card they stand for
+    played_as: String,
+    /// From 1, the weakest hand
+    rank: usize,
+    bid: usize
+}
+
+impl HandReport {
+    fn get_winnings(&self) -> usize {
+        self.bid * self.rank
+    }
+}
+
+/// Every card, from the strongest to the weakest
+const STANDARD_ORDER: &str = "AKQJT98765432";
+
+/// How two hands of the same combo type are ordered
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TieBreak {
+    /// The hand with the strongest first card wins, then the second one...
+    CardOrder,
+    /// Cards are compared from the largest group to the smallest, the strongest first inside a group,
+    /// wild cards counting as the card they stand for
+    Poker
+}
+
+/// How hands are evaluated: the strength of the cards, the wild ones, and the tie-break policy
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RuleSet {
+    /// Every card, from the strongest to the weakest
+    order: Vec<char>,
+    /// Cards joining the largest group of cards of the hand
+    wild: Vec<char>,
+    tie_break: TieBreak
+}
+
+impl RuleSet {
+    /// `order` has to rank every card once, and the wild cards have to be part of it
+    pub fn new(order: &str, wild: &str, tie_break: TieBreak) -> Result<Self, String> {
+        let order: Vec<char> = order.chars().collect();
+        for card in STANDARD_ORDER.chars() {
+            if order.iter().filter(|&&order_card| order_card == card).count() != 1 {
+                return Err(format!("The card order '{}' has to contain the card '{card}' exactly once", order.iter().collect::<String>()));
+            }
+        }
+        if order.len() != STANDARD_ORDER.len() {
+            return Err(format!("The card order '{}' has to contain the {} cards '{STANDARD_ORDER}'", order.iter().collect::<String>(), STANDARD_ORDER.len()));
+        }
+
+        let wild: Vec<char> = wild.chars().collect();
+        if let Some(card) = wild.iter().find(|card| !order.contains(card)) {
+            return Err(format!("Unknown wild card: '{card}'"));
+        }
+        Ok(Self { order, wild, tie_break })
+    }
+
+    /// The rules of the first part
+    pub fn standard() -> Self {
+        Self { order: STANDARD_ORDER.chars().collect(), wild: Vec::new(), tie_break: TieBreak::CardOrder }
+    }
+
+    /// The rules of the second part: J cards are wild, and the weakest cards
+    pub fn jokers() -> Self {
+        Self { order: "AKQT98765432J".chars().collect(), wild: vec!['J'], tie_break: TieBreak::CardOrder }
+    }
+
+    /// Strength of the card, the higher the stronger
+    fn get_strength(&self, card: char) -> usize {
+        match self.order.iter().position(|&order_card| order_card == card) {
+            Some(index) => self.order.len() - index,
+            None => 0
+        }
+    }
+
+    fn is_wild(&self, card: char) -> bool {
+        self.wild.contains(&card)
+    }
+
+    /// Returns the groups of identical cards as (card, size), the largest first then the strongest first.
+    /// The wild cards are added to the first group, or make a group of the strongest card if the hand is only wild cards
+    fn get_groups(&self, cards: &[char; 5]) -> Vec<(char, usize)> {
+        let mut groups = Vec::<(char, usize)>::new();
+        let mut nb_wild: usize = 0;
+        for &card in cards {
+            if self.is_wild(card) {
+                nb_wild += 1;
+            }
+            else if let Some(group) = groups.iter_mut().find(|group| group.0 == card) {
+                group.1 += 1;
+            }
+            else {
+                groups.push((card, 1));
+            }
+        }
+        groups.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| self.get_strength(b.0).cmp(&self.get_strength(a.0))));
+
+        match groups.first_mut() {
+            Some(group) => {
+                group.1 += nb_wild;
+            }
+            None => {
+                groups.push((self.order[0], nb_wild));
+            }
+        }
+        groups
+    }
+
+    /// Returns what the hand is ranked on: its combo type, then the card strengths given by the tie-break policy
+    pub fn evaluate(&self, hand: &Hand) -> (ComboType, Vec<usize>) {
+        let groups = self.get_groups(&hand.cards);
+        let sizes: Vec<usize> = groups.iter().map(|group| group.1).collect();
+        let combo_type = match sizes.as_slice() {
+            [5] => ComboType::FiveOfAKind,
+            [4, ..] => ComboType::FourOfAKind,
+            [3, 2] => ComboType::FullHouse,
+            [3, ..] => ComboType::ThreeOfAKind,
+            [2, 2, ..] => ComboType::TwoPair,
+            [2, ..] => ComboType::OnePair,
+            _ => ComboType::HighCard
+        };
+
+        let strengths: Vec<usize> = match self.tie_break {
+            TieBreak::CardOrder => hand.cards.iter().map(|&card| self.get_strength(card)).collect(),
+            TieBreak::Poker => groups.iter()
+                .flat_map(|&(card, size)| std::iter::repeat_n(self.get_strength(card), size))
+                .collect()
+        };
+        (combo_type, strengths)
+    }
+
+    /// Returns the cards of the hand, the wild ones being replaced by the card they stand for
+    pub fn get_played_cards(&self, hand: &Hand) -> [char; 5] {
+        let substitute = self.get_groups(&hand.cards)[0].0;
+        hand.cards.map(|card| if self.is_wild(card) { substitute } else { card })
+    }
+
+    /// Orders the hands from the weakest to the strongest
+    pub fn compare(&self, hand: &Hand, other: &Hand) -> Ordering {
+        self.evaluate(hand).cmp(&self.evaluate(other))
+    }
+}
+
+/// The type of a hand, from the weakest to the strongest
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum ComboType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand {
+    cards: [char; 5],
+    bid: usize
+}
+
+impl Hand {
+    fn new(cards: [char; 5], bid: usize) -> Self {
+        Self {
+            cards,
+            bid
+        }
+    }
+}
+
+
+fn get_input(input: &Input) -> Result<Vec<Hand>, InputError> {
+    let mut hands = Vec::<Hand>::new();
+    for (index_line, line) in input.lines().into_iter().enumerate() {
+        let (cards_text, bid_text) = match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [cards_text, bid_text] => (*cards_text, *bid_text),
+            _ => {
+                return Err(input.error(index_line + 1, format!("Expected '<cards> <bid>', found '{line}'")));
+            }
+        };
+
+        if let Some(card_char) = cards_text.chars().find(|card_char| !STANDARD_ORDER.contains(*card_char)) {
+            return Err(input.error(index_line + 1, format!("Unknown card: '{card_char}'")));
+        }
+        let cards: [char; 5] = match cards_text.chars().collect::<Vec<char>>().try_into() {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(input.error(index_line + 1, format!("A hand has 5 cards, found '{cards_text}'")));
+            }
+        };
+        let bid: usize = match bid_text.parse::<usize>() {
+            Ok(val) => val,
+            Err(error) => {
+                return Err(input.error(index_line + 1, format!("Error while parsing the bid: '{bid_text}'. Error: {error}")));
+            }
+        };
+        hands.push(Hand::new(cards, bid));
+    }
+    Ok(hands)
+}
+
+/// Returns the index of the hands from the weakest to the strongest, their rank being their position + 1
+fn get_ranked_hands(hands: &[Hand], rules: &RuleSet) -> Vec<usize> {
+    let mut ranked_hands: Vec<(usize, (ComboType, Vec<usize>))> = hands.iter().map(|hand| rules.evaluate(hand)).enumerate().collect();
+    ranked_hands.sort_by(|a, b| a.1.cmp(&b.1));
+    ranked_hands.into_iter().map(|(index, _)| index).collect()
+}
+
+/// Returns how every hand was ranked, from the weakest to the strongest
+fn get_hand_reports(hands: &[Hand], rules: &RuleSet) -> Vec<HandReport> {
+    let mut reports = Vec::<HandReport>::with_capacity(hands.len());
+    for (rank_index, index) in get_ranked_hands(hands, rules).into_iter().enumerate() {
+        let hand = &hands[index];
+        reports.push(HandReport {
+            line: index + 1,
+            cards: hand.cards.iter().collect(),
+            combo_type: rules.evaluate(hand).0,
+            played_as: rules.get_played_cards(hand).iter().collect(),
+            rank: rank_index + 1,
+            bid: hand.bid
+        });
+    }
+    reports
+}
+
+/// Sum the winnings (bid * rank) of every hand
+fn get_total_winnings(hands: &[Hand], rules: &RuleSet) -> usize {
+    let mut result: usize = 0;
+    for (rank_index, index) in get_ranked_hands(hands, rules).into_iter().enumerate() {
+        result += hands[index].bid * (rank_index + 1);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        Hand::new(cards.chars().collect::<Vec<char>>().try_into().unwrap(), 1)
+    }
+
+    #[test]
+    fn presets_give_the_example_answers() {
+        let hands = Day07::parse(include_str!("../files/input_debug.txt")).unwrap();
+        assert_eq!(get_total_winnings(&hands, &RuleSet::standard()), 6440);
+        assert_eq!(get_total_winnings(&hands, &RuleSet::jokers()), 5905);
+    }
+
+    #[test]
+    fn hands_need_5_cards_and_a_bid() {
+        assert_eq!(Day07::parse("32T3K 765\nK2 5\n").err().unwrap().to_string(), "line 2: A hand has 5 cards, found 'K2'");
+        assert_eq!(Day07::parse("32T3K\n").err().unwrap().to_string(), "line 1: Expected '<cards> <bid>', found '32T3K'");
+        assert!(Day07::parse("32T3K 765 12\n").is_err());
+        assert!(Day07::parse("32T3KA 765\n").is_err());
+        assert!(Day07::parse("32X3K 765\n").is_err());
+    }
+
+    #[test]
+    fn several_wild_ranks() {
+        let rules = RuleSet::new("AKQJT98765432", "J2", TieBreak::CardOrder).unwrap();
+        assert_eq!(rules.evaluate(&hand("J2KK3")).0, ComboType::FourOfAKind);
+        assert_eq!(rules.evaluate(&hand("JJ222")).0, ComboType::FiveOfAKind);
+        assert!(RuleSet::new("AKQJT98765432", "X", TieBreak::CardOrder).is_err());
+        assert!(RuleSet::new("AKQJT9876543", "", TieBreak::CardOrder).is_err());
+    }
+
+    #[test]
+    fn poker_tie_break_compares_the_groups_first() {
+        let card_order = RuleSet::standard();
+        let poker = RuleSet::new(STANDARD_ORDER, "", TieBreak::Poker).unwrap();
+        // Pair of 3 with an ace, against a pair of kings
+        assert_eq!(card_order.compare(&hand("A33QT"), &hand("KK234")), Ordering::Greater);
+        assert_eq!(poker.compare(&hand("A33QT"), &hand("KK234")), Ordering::Less);
+    }
+
+    #[test]
+    fn explained_winnings_add_up() {
+        let hands = Day07::parse(include_str!("../files/input_debug.txt")).unwrap();
+        let reports = get_hand_reports(&hands, &RuleSet::jokers());
+        assert_eq!(reports.iter().map(|report| report.get_winnings()).sum::<usize>(), 5905);
+
+        // KTJJT is played as KTTTT, the strongest hand with the jokers
+        let strongest = reports.last().unwrap();
+        assert_eq!((strongest.line, strongest.combo_type, strongest.played_as.as_str()), (4, ComboType::FourOfAKind, "KTTTT"));
+
+        let csv = Explain { sort: ExplainSort::Line, csv: true }.get_report(2, &reports);
+        assert_eq!(csv.lines().nth(1), Some("2,1,1,32T3K,OnePair,32T3K,765,765"));
+    }
+}
@@ -0,0 +1,5 @@
+use d07::Day07;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day07>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+
+use aoc_input::{Input, InputError};
+use aoc_solution::Solution;
+use num::integer::{lcm, Integer};
+
+/// Day 8: Haunted Wasteland
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        let (nodes, directions) = get_input(&Input::new(input))?;
+        Ok(Network { nodes, directions })
+    }
+
+    /// Count the steps required to go from AAA to ZZZ
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        let starting_node_id = "AAA";
+        let ending_node_id = "ZZZ";
+
+        let res = compute_steps(starting_node_id, &input.nodes, &input.directions, |node_id| node_id == ending_node_id)?;
+
+        Ok(res.to_string())
+    }
+
+    /// Count the steps required for every ghost to stand on a node ending with Z at the same time
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        // Get nodes that ends with an A
+        let mut starting_nodes = Vec::<&Node>::new();
+        for (node_id, node) in input.nodes.iter() {
+            if node_id.ends_with('A') {
+                starting_nodes.push(node);
+            }
+        }
+
+        // Find the cycle walked by each ghost
+        let mut ghost_cycles = Vec::<GhostCycle>::new();
+        for starting_node in starting_nodes {
+            ghost_cycles.push(find_ghost_cycle(&starting_node.id, &input.nodes, &input.directions, |node_id| node_id.ends_with('Z'))?);
+        }
+
+        match get_first_common_ending_step(&ghost_cycles) {
+            Some(res) => Ok(res.to_string()),
+            None => Err("The ghosts never stand on ending nodes at the same time".to_string())
+        }
+    }
+}
+
+/// The map of the desert: the left/right instructions and the network of nodes
+pub struct Network {
+    nodes: HashMap<String, Node>,
+    directions: Vec<Direction>
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    id: String,
+    left: String,
+    right: String
+}
+
+impl Node {
+    fn new(id: &str, left: &str, right: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            left: left.to_string(),
+            right: right.to_string()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Right,
+    Left
+}
+
+
+/// Parse the input of the AOC D8 into a HashMap of Nodes and a Vec of Directions
+fn get_input(input: &Input) -> Result<(HashMap<String, Node>, Vec<Direction>), InputError> {
+    
+    // Returned processed input
+    let mut directions = Vec::<Direction>::new();
+    let mut nodes: HashMap<String, Node> = HashMap::new();
+
+
+    // Get the content 
+
+    // Iterate over lines 
+    for (line_index, line) in input.lines().into_iter().enumerate() {
+        // Process the directions
+        if line_index == 0 {
+            for dir in line.chars() {
+                match dir {
+                    'L' => {
+                        directions.push(Direction::Left);
+                    }
+                    'R' => {
+                        directions.push(Direction::Right);
+                    }
+                    _ => {
+                        return Err(input.error(line_index + 1, format!("Unknown direction: '{dir}'")));
+                    }
+                }
+            }
+        }
+        // Skip the line 1 and process the other as Nodes
+        // AAA = (BBB, CCC)
+        else if line_index > 1 {
+            let mut id: &str = "000";
+            let mut left: &str = "000";
+            let mut right: &str = "000";
+            for (eq_split_index, eq_split) in line.split('=').enumerate() {
+                if eq_split_index == 0 {
+                    id = eq_split.trim();
+                }
+                else {
+                    for (comma_eq_split_index, comma_eq_split) in eq_split.split(',').enumerate() {
+                        if comma_eq_split_index == 0 {
+                            left = comma_eq_split.trim().trim_matches('(').trim();
+                        }
+                        else {
+                            right = comma_eq_split.trim().trim_matches(')').trim();
+                        }
+                    }
+                }
+            }
+            // Insert nodes into the HashMap
+            let node = Node::new(id, left, right);
+            nodes.insert(node.id.clone(), node);
+        }
+    }
+
+    Ok((nodes, directions))
+}
+
+
+/// Compute the required amount of steps to get to an ending node from the starting node
+fn compute_steps<F>(starting_node_id: &str, node_hashmap: &HashMap<String, Node>, directions: &[Direction], is_ending_node: F) -> Result<usize, String>
+where F: Fn(&str) -> bool
+{
+    if directions.is_empty() {
+        return Err("No direction to follow".to_string());
+    }
+    let mut count: usize = 0;
+    let mut current_node_id: &str = starting_node_id;
+
+    loop {
+        for dir in directions {
+            let current_node = match node_hashmap.get(current_node_id) {
+                Some(val) => val,
+                None => {
+                    return Err(format!("Unknown node: '{current_node_id}'"));
+                }
+            };
+            current_node_id = match *dir {
+                Direction::Left => &current_node.left,
+                Direction::Right => &current_node.right
+            };
+            count += 1;
+            if is_ending_node(current_node_id) {
+                return Ok(count);
+            }
+        }
+    }
+}
+
+/// The walk of a ghost, which always ends up looping as there is a finite number of (node, direction index) states
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GhostCycle {
+    /// Number of steps before entering the cycle
+    pub start: usize,
+    /// Number of steps of the cycle
+    pub length: usize,
+    /// Steps standing on an ending node, before `start + length`
+    pub ending_steps: Vec<usize>
+}
+
+impl GhostCycle {
+    /// Returns true if the ghost stands on an ending node after the given number of steps
+    pub fn is_ending_step(&self, step: usize) -> bool {
+        if step < self.start {
+            self.ending_steps.contains(&step)
+        }
+        else {
+            self.ending_steps.contains(&(self.start + (step - self.start) % self.length))
+        }
+    }
+
+    /// The shortcut the puzzle inputs are crafted for: a single ending node, reached every `length` steps
+    fn is_lcm_compatible(&self) -> bool {
+        self.start <= self.length && self.ending_steps == [self.length]
+    }
+}
+
+/// Walk from the starting node until a (node, direction index) state is visited again
+pub fn find_ghost_cycle<F>(starting_node_id: &str, node_hashmap: &HashMap<String, Node>, directions: &[Direction], is_ending_node: F) -> Result<GhostCycle, String>
+where F: Fn(&str) -> bool
+{
+    if directions.is_empty() {
+        return Err("No direction to follow".to_string());
+    }
+    let mut visited: HashMap<(&str, usize), usize> = HashMap::new();
+    let mut ending_steps: Vec<usize> = Vec::new();
+    let mut step: usize = 0;
+    let mut current_node_id: &str = starting_node_id;
+
+    loop {
+        let dir_index = step % directions.len();
+        if let Some(first_step) = visited.insert((current_node_id, dir_index), step) {
+            return Ok(GhostCycle { start: first_step, length: step - first_step, ending_steps });
+        }
+        if is_ending_node(current_node_id) {
+            ending_steps.push(step);
+        }
+
+        let current_node = match node_hashmap.get(current_node_id) {
+            Some(val) => val,
+            None => {
+                return Err(format!("Unknown node: '{current_node_id}'"));
+            }
+        };
+        current_node_id = match directions[dir_index] {
+            Direction::Left => &current_node.left,
+            Direction::Right => &current_node.right
+        };
+        step += 1;
+    }
+}
+
+/// Returns the first step (after the start) where every ghost stands on an ending node, if any.
+/// Uses the LCM of the cycle lengths when the cycles allow it, and the Chinese Remainder Theorem otherwise
+pub fn get_first_common_ending_step(ghost_cycles: &[GhostCycle]) -> Option<usize> {
+    if ghost_cycles.iter().all(|ghost_cycle| ghost_cycle.is_lcm_compatible()) {
+        return Some(ghost_cycles.iter().fold(1, |res, ghost_cycle| lcm(res, ghost_cycle.length)));
+    }
+
+    // Before every ghost is in its cycle, the ending steps have to be checked one by one
+    let all_cycling_step: usize = ghost_cycles.iter().map(|ghost_cycle| ghost_cycle.start).max().unwrap_or(0).max(1);
+    if let Some(first_ghost) = ghost_cycles.first() {
+        for step in 1..all_cycling_step {
+            if first_ghost.is_ending_step(step) && ghost_cycles.iter().all(|ghost_cycle| ghost_cycle.is_ending_step(step)) {
+                return Some(step);
+            }
+        }
+    }
+
+    // Afterwards each ghost stands on an ending node at steps congruent to one of its ending steps, modulo its cycle length
+    let mut congruences: Vec<(i128, i128)> = vec![(0, 1)];
+    for ghost_cycle in ghost_cycles {
+        let length = ghost_cycle.length as i128;
+        let mut new_congruences: Vec<(i128, i128)> = Vec::new();
+        for congruence in &congruences {
+            for ending_step in ghost_cycle.ending_steps.iter().filter(|step| **step >= ghost_cycle.start) {
+                if let Some(val) = combine_congruences(*congruence, (*ending_step as i128 % length, length)) {
+                    new_congruences.push(val);
+                }
+            }
+        }
+        congruences = new_congruences;
+    }
+
+    // Smallest step of each congruence class once every ghost is cycling
+    let all_cycling_step = all_cycling_step as i128;
+    congruences.into_iter()
+        .map(|(remainder, modulus)| all_cycling_step + (remainder - all_cycling_step).rem_euclid(modulus))
+        .min()
+        .and_then(|step| usize::try_from(step).ok())
+}
+
+/// Returns the congruence equivalent to both `x ≡ a.0 (mod a.1)` and `x ≡ b.0 (mod b.1)`, if they are compatible
+fn combine_congruences(a: (i128, i128), b: (i128, i128)) -> Option<(i128, i128)> {
+    let gcd_result = a.1.extended_gcd(&b.1);
+    let gcd = gcd_result.gcd;
+    if (b.0 - a.0) % gcd != 0 {
+        return None;
+    }
+    // a.0 + a.1 * k ≡ b.0 (mod b.1), with a.1 * x ≡ gcd (mod b.1)
+    let modulus = a.1 / gcd * b.1;
+    let k = ((b.0 - a.0) / gcd % (b.1 / gcd)) * gcd_result.x % (b.1 / gcd);
+    Some(((a.0 + a.1 * k).rem_euclid(modulus), modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_network(content: &str) -> Network {
+        Day08::parse(content).unwrap()
+    }
+
+    fn get_cycles(network: &Network) -> Vec<GhostCycle> {
+        let mut starting_ids: Vec<&String> = network.nodes.keys().filter(|node_id| node_id.ends_with('A')).collect();
+        starting_ids.sort();
+        starting_ids.into_iter()
+            .map(|node_id| find_ghost_cycle(node_id, &network.nodes, &network.directions, |node_id| node_id.ends_with('Z')).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn walks_fail_on_unknown_nodes_and_missing_directions() {
+        let network = get_network(include_str!("../files/input_debug_2.txt"));
+        assert_eq!(Day08::part1(&network), Err("Unknown node: 'AAA'".to_string()));
+        let network = get_network("L\n\nAAA = (BBB, BBB)\n");
+        assert_eq!(Day08::part1(&network), Err("Unknown node: 'BBB'".to_string()));
+        let network = get_network("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n");
+        assert_eq!(Day08::part1(&network), Err("No direction to follow".to_string()));
+    }
+
+    #[test]
+    fn cycles_are_detected() {
+        let network = get_network("L\n\n1AA = (1ZZ, 1ZZ)\n1ZZ = (1BB, 1BB)\n1BB = (1ZZ, 1ZZ)");
+        assert_eq!(get_cycles(&network), vec![GhostCycle { start: 1, length: 2, ending_steps: vec![1] }]);
+    }
+
+    #[test]
+    fn lcm_is_used_when_valid() {
+        let network = get_network("L\n\n1AA = (1BB, 1BB)\n1BB = (1ZZ, 1ZZ)\n1ZZ = (1BB, 1BB)\n2AA = (2BB, 2BB)\n2BB = (2CC, 2CC)\n2CC = (2ZZ, 2ZZ)\n2ZZ = (2BB, 2BB)");
+        assert_eq!(get_first_common_ending_step(&get_cycles(&network)), Some(6));
+    }
+
+    #[test]
+    fn crt_is_used_when_lcm_is_wrong() {
+        // Ghost 1 ends on odd steps, ghost 2 on multiples of 3: the LCM of their cycles would be 6
+        let network = get_network("L\n\n1AA = (1ZZ, 1ZZ)\n1ZZ = (1BB, 1BB)\n1BB = (1ZZ, 1ZZ)\n2AA = (2BB, 2BB)\n2BB = (2CC, 2CC)\n2CC = (2ZZ, 2ZZ)\n2ZZ = (2BB, 2BB)");
+        assert_eq!(get_first_common_ending_step(&get_cycles(&network)), Some(3));
+    }
+
+    #[test]
+    fn ending_steps_before_the_cycles_are_checked() {
+        // Ghost 3 only stands on an ending node at step 1
+        let network = get_network("L\n\n1AA = (1ZZ, 1ZZ)\n1ZZ = (1BB, 1BB)\n1BB = (1ZZ, 1ZZ)\n3AA = (3ZZ, 3ZZ)\n3ZZ = (3BB, 3BB)\n3BB = (3BB, 3BB)");
+        assert_eq!(get_first_common_ending_step(&get_cycles(&network)), Some(1));
+
+        let network = get_network("L\n\n2AA = (2BB, 2BB)\n2BB = (2CC, 2CC)\n2CC = (2ZZ, 2ZZ)\n2ZZ = (2BB, 2BB)\n3AA = (3ZZ, 3ZZ)\n3ZZ = (3BB, 3BB)\n3BB = (3BB, 3BB)");
+        assert_eq!(get_first_common_ending_step(&get_cycles(&network)), None);
+    }
+}
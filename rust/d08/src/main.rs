@@ -0,0 +1,5 @@
+use d08::Day08;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main::<Day08>(env!("CARGO_MANIFEST_DIR"))
+}
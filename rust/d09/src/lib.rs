@@ -0,0 +1,35 @@
+use aoc_input::{Input, InputError};
+use aoc_math::sequence::{extrapolate_next, extrapolate_previous};
+use aoc_solution::Solution;
+
+/// Day 9: Mirage Maintenance
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        // Every line is a sequence of whitespace separated numbers
+        Input::new(input).numbers::<i64>()
+    }
+
+    /// Sum the extrapolated next values of every sequence
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        let mut res: i64 = 0;
+        for seq in input {
+            res += extrapolate_next(seq, 1)[0];
+        }
+
+        Ok(res.to_string())
+    }
+
+    /// Sum the extrapolated previous values of every sequence
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        let mut res: i64 = 0;
+        for seq in input {
+            res += extrapolate_previous(seq, 1)[0];
+        }
+
+        Ok(res.to_string())
+    }
+}
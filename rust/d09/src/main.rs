@@ -0,0 +1,5 @@
+use d09::Day09;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main::<Day09>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,198 @@
+use std::{fmt::Write, path::Path};
+
+use aoc_grid::Grid;
+use aoc_math::geometry::Direction;
+
+use super::{Tile, TileStatus};
+
+/// Side of a tile in the exported images, in pixels
+const TILE_SIZE: usize = 8;
+
+const BACKGROUND: [u8; 3] = [0xff, 0xff, 0xff];
+/// Pipes that aren't part of the loop
+const PIPE: [u8; 3] = [0x9e, 0x9e, 0x9e];
+const LOOP_PIPE: [u8; 3] = [0xd3, 0x2f, 0x2f];
+
+/// Returns the colour filling the tile, None for the tiles not classified
+fn get_fill(status: TileStatus) -> Option<[u8; 3]> {
+    match status {
+        TileStatus::Start => Some([0x9c, 0x27, 0xb0]),
+        TileStatus::In => Some([0x4c, 0xaf, 0x50]),
+        TileStatus::Out => Some([0xb3, 0xe5, 0xfc]),
+        TileStatus::Loop | TileStatus::Undefined => None
+    }
+}
+
+fn is_loop(status: TileStatus) -> bool {
+    status == TileStatus::Loop || status == TileStatus::Start
+}
+
+fn to_hex(colour: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", colour[0], colour[1], colour[2])
+}
+
+/// Writes the map to an SVG or a PNG image, depending on the extension of the path
+pub fn export_map(tiles: &Grid<Tile>, path: &Path) -> Result<(), String> {
+    let content = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => to_svg(tiles).into_bytes(),
+        Some("png") => to_png(tiles),
+        _ => {
+            return Err(format!("Unknown image format for {}, expected a .svg or a .png file", path.display()));
+        }
+    };
+    match std::fs::write(path, content) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(format!("Error while writing {}: {error}", path.display()))
+    }
+}
+
+/// Renders the map as SVG: the tiles filled by status, and the pipes drawn from the tile center to each connected side
+pub fn to_svg(tiles: &Grid<Tile>) -> String {
+    let (width, height) = (tiles.width() * TILE_SIZE, tiles.height() * TILE_SIZE);
+    let half = TILE_SIZE / 2;
+    let mut fills = String::new();
+    let mut pipes = String::new();
+    let mut loop_pipes = String::new();
+
+    for ((x, y), tile) in tiles.iter() {
+        let (left, top) = (x * TILE_SIZE, y * TILE_SIZE);
+        if let Some(fill) = get_fill(tile.status) {
+            let _ = writeln!(fills, r#"<rect x="{left}" y="{top}" width="{TILE_SIZE}" height="{TILE_SIZE}" fill="{}"/>"#, to_hex(fill));
+        }
+
+        let path = if is_loop(tile.status) { &mut loop_pipes } else { &mut pipes };
+        let (center_x, center_y) = (left + half, top + half);
+        for direction in tile.tile_type.get_connections() {
+            let (end_x, end_y) = match direction {
+                Direction::North => (center_x, top),
+                Direction::East => (left + TILE_SIZE, center_y),
+                Direction::South => (center_x, top + TILE_SIZE),
+                Direction::West => (left, center_y)
+            };
+            let _ = write!(path, "M{center_x} {center_y}L{end_x} {end_y}");
+        }
+    }
+
+    let mut res = String::new();
+    let _ = writeln!(res, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#);
+    let _ = writeln!(res, r#"<rect width="{width}" height="{height}" fill="{}"/>"#, to_hex(BACKGROUND));
+    res += &fills;
+    let _ = writeln!(res, r#"<path d="{pipes}" stroke="{}" stroke-width="1" stroke-linecap="square" fill="none"/>"#, to_hex(PIPE));
+    let _ = writeln!(res, r#"<path d="{loop_pipes}" stroke="{}" stroke-width="2" stroke-linecap="square" fill="none"/>"#, to_hex(LOOP_PIPE));
+    res += "</svg>\n";
+    res
+}
+
+/// Renders the map as a PNG image, with the same drawing as the SVG
+pub fn to_png(tiles: &Grid<Tile>) -> Vec<u8> {
+    let (width, height) = (tiles.width() * TILE_SIZE, tiles.height() * TILE_SIZE);
+    let mut pixels: Vec<u8> = BACKGROUND.repeat(width * height);
+    let mut fill_rect = |x_range: std::ops::Range<usize>, y_range: std::ops::Range<usize>, colour: [u8; 3]| {
+        for y in y_range {
+            for x in x_range.clone() {
+                let index = 3 * (y * width + x);
+                pixels[index..index + 3].copy_from_slice(&colour);
+            }
+        }
+    };
+
+    let half = TILE_SIZE / 2;
+    for ((x, y), tile) in tiles.iter() {
+        let (left, top) = (x * TILE_SIZE, y * TILE_SIZE);
+        if let Some(fill) = get_fill(tile.status) {
+            fill_rect(left..left + TILE_SIZE, top..top + TILE_SIZE, fill);
+        }
+
+        // Arms of the pipe, 2 pixels wide for the loop
+        let (colour, thickness) = if is_loop(tile.status) { (LOOP_PIPE, 2) } else { (PIPE, 1) };
+        let (center_x, center_y) = (left + half, top + half);
+        let (arm_start_x, arm_start_y) = (center_x + 1 - thickness, center_y + 1 - thickness);
+        for direction in tile.tile_type.get_connections() {
+            match direction {
+                Direction::North => fill_rect(arm_start_x..center_x + 1, top..center_y + 1, colour),
+                Direction::East => fill_rect(arm_start_x..left + TILE_SIZE, arm_start_y..center_y + 1, colour),
+                Direction::South => fill_rect(arm_start_x..center_x + 1, arm_start_y..top + TILE_SIZE, colour),
+                Direction::West => fill_rect(left..center_x + 1, arm_start_y..center_y + 1, colour)
+            }
+        }
+    }
+
+    encode_png(width, height, &pixels)
+}
+
+/// Encodes 8 bits RGB pixels in a PNG, the zlib stream only using stored (uncompressed) deflate blocks
+fn encode_png(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    // Each scanline starts with its filter type, 0 for None
+    let mut raw = Vec::<u8>::with_capacity(height * (3 * width + 1));
+    for line in pixels.chunks(3 * width) {
+        raw.push(0);
+        raw.extend_from_slice(line);
+    }
+
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        zlib.push(u8::from(blocks.peek().is_none()));
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::<u8>::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, default compression/filter/no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut res = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    write_chunk(&mut res, b"IHDR", &header);
+    write_chunk(&mut res, b"IDAT", &zlib);
+    write_chunk(&mut res, b"IEND", &[]);
+    res
+}
+
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_known_values() {
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+}
@@ -0,0 +1,669 @@
+use std::path::PathBuf;
+
+use aoc_grid::Grid;
+use aoc_input::{Input, InputError};
+use aoc_math::geometry::{interior_points, polygon_double_area, Direction, Point2};
+use aoc_solution::{Configurable, Solution};
+
+mod export;
+mod visualizer;
+
+use visualizer::Visualizer;
+pub use visualizer::Visualization;
+
+/// Day 10: Pipe Maze
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeMap;
+
+    fn parse(input: &str) -> Result<Self::Input, InputError> {
+        get_input(&Input::new(input))
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Self::part1_with(input, &Day10Options::default())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Self::part2_with(input, &Day10Options::default())
+    }
+}
+
+impl Configurable for Day10 {
+    type Options = Day10Options;
+
+    const OPTIONS: &'static str = "Day 10 options:
+    --area <method>            How the enclosed tiles are counted: 'shoelace' (default), 'ray-cast',
+                               or 'cross-check' failing when both don't agree
+    --visualize                Animate the walk along the loop in the terminal, and the in/out
+                               classification of the tiles with 'ray-cast' or 'cross-check'
+    --fps <n>                  Frames per second of the animation (default 60), implies --visualize
+    --steps-per-frame <n>      Tiles walked or classified between two frames (default 1), implies --visualize
+    --export <path>            Write the map classified by part 2 to an image, SVG or PNG depending
+                               on the extension of the path";
+
+    fn parse_options(args: &[String]) -> Result<Self::Options, String> {
+        let mut options = Day10Options::default();
+        let mut args_iter = args.iter();
+        while let Some(option) = args_iter.next() {
+            match option.as_str() {
+                "--area" => {
+                    options.area = match args_iter.next().map(|method| method.as_str()) {
+                        Some("shoelace") => AreaMethod::Shoelace,
+                        Some("ray-cast") => AreaMethod::RayCast,
+                        Some("cross-check") => AreaMethod::CrossCheck,
+                        Some(method) => {
+                            return Err(format!("Unknown area method: '{method}'"));
+                        }
+                        None => {
+                            return Err("Missing area method after --area".to_string());
+                        }
+                    };
+                }
+                "--visualize" => {
+                    options.visualization.get_or_insert_with(Visualization::default);
+                }
+                "--fps" => {
+                    options.visualization.get_or_insert_with(Visualization::default).fps = parse_positive(option, args_iter.next())?;
+                }
+                "--steps-per-frame" => {
+                    options.visualization.get_or_insert_with(Visualization::default).steps_per_frame = parse_positive(option, args_iter.next())?;
+                }
+                "--export" => {
+                    options.export = match args_iter.next() {
+                        Some(path) => Some(PathBuf::from(path)),
+                        None => {
+                            return Err("Missing path after --export".to_string());
+                        }
+                    };
+                }
+                _ => {
+                    return Err(format!("Unexpected argument: '{option}'"));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Returns the number of steps to reach the farthest point of the loop
+    fn part1_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        let mut tiles = input.tiles.clone();
+        let mut visualizer = start_visualizer(options.visualization, &tiles)?;
+        let loop_coords = match follow_pipes(&mut tiles, &input.start, visualizer.as_mut()) {
+            Ok(val) => val,
+            Err(error) => {
+                return Err(format!("Error while following pipe: Error: {error}"));
+            }
+        };
+        if let Some(visualizer) = visualizer.as_mut() {
+            visualizer.finish(&tiles)?;
+        }
+        Ok((loop_coords.len() / 2).to_string())
+    }
+
+    /// Returns the number of tiles enclosed by the loop
+    fn part2_with(input: &Self::Input, options: &Self::Options) -> Result<String, String> {
+        if let Some(path) = &options.export {
+            // The image needs the in/out status of every tile, which only the ray casting gives
+            let (tiles, _) = classify_tiles(input, None)?;
+            export::export_map(&tiles, path)?;
+        }
+
+        match options.area {
+            AreaMethod::Shoelace => Ok(count_enclosed_tiles_shoelace(input, options.visualization)?.to_string()),
+            AreaMethod::RayCast => Ok(count_enclosed_tiles(input, options.visualization)?.to_string()),
+            AreaMethod::CrossCheck => {
+                let shoelace = count_enclosed_tiles_shoelace(input, None)?;
+                let ray_cast = count_enclosed_tiles(input, options.visualization)?;
+                if shoelace != ray_cast {
+                    return Err(format!("The shoelace formula found {shoelace} enclosed tiles, the ray casting {ray_cast}"));
+                }
+                Ok(shoelace.to_string())
+            }
+        }
+    }
+}
+
+fn parse_positive<T: std::str::FromStr + Default + PartialEq>(option: &str, value: Option<&String>) -> Result<T, String> {
+    match value.map(|val| val.parse::<T>()) {
+        Some(Ok(val)) if val != T::default() => Ok(val),
+        Some(_) => Err(format!("{option} expects a positive number")),
+        None => Err(format!("Missing value after {option}"))
+    }
+}
+
+/// The options of the day 10 binary
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Day10Options {
+    pub area: AreaMethod,
+    /// Animate the map in the terminal while solving, if set
+    pub visualization: Option<Visualization>,
+    /// Image the classified map is written to, if set
+    pub export: Option<PathBuf>
+}
+
+/// How the tiles enclosed by the loop are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AreaMethod {
+    /// Area of the loop polygon with the shoelace formula, turned into a number of tiles with Pick's theorem
+    #[default]
+    Shoelace,
+    /// Count the loop crossings from every tile to the border of the map
+    RayCast,
+    /// Run both, and fail if they don't agree
+    CrossCheck
+}
+
+/// The parsed maze
+pub struct PipeMap {
+    tiles: Grid<Tile>,
+    start: Coords
+}
+
+/// Represents the tile type (pipe), named after their possible connection
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+enum TileType {
+    /// '.'
+    Ground,
+    /// '|'
+    NorthSouth,
+    /// '-'
+    EastWest, 
+    /// 'L'
+    NorthEast,
+    /// 'J'
+    NorthWest,
+    /// '7'
+    SouthWest,
+    /// 'F'
+    SouthEast,
+    /// '?'
+    Unknown
+}
+
+impl TileType {
+    /// Returns the tile char representing the pipe
+    fn get_char(&self) -> char {
+        // match self {
+        //     &Self::Ground => { '.' }
+        //     &Self::NorthSouth => { '|' }
+        //     &Self::EastWest => { '-' }
+        //     &Self::NorthEast => { 'L' }
+        //     &Self::NorthWest => { 'J' }
+        //     &Self::SouthWest => { '7' }
+        //     &Self::SouthEast => { 'F' }
+        //     &Self::Unknown => { '?' }
+        // }
+        match *self {
+            Self::Ground => { 'X' }
+            Self::NorthSouth => { '│' }
+            Self::EastWest => { '─' }
+            Self::NorthEast => { '└' }
+            Self::NorthWest => { '┘' }
+            Self::SouthWest => { '┐' }
+            Self::SouthEast => { '┌' }
+            Self::Unknown => { '?' }
+        }
+    }
+
+    /// Returns the sides of the tile connected by the pipe
+    fn get_connections(&self) -> &'static [Direction] {
+        match *self {
+            Self::NorthSouth => { &[Direction::North, Direction::South] }
+            Self::EastWest => { &[Direction::East, Direction::West] }
+            Self::NorthEast => { &[Direction::North, Direction::East] }
+            Self::NorthWest => { &[Direction::North, Direction::West] }
+            Self::SouthWest => { &[Direction::South, Direction::West] }
+            Self::SouthEast => { &[Direction::South, Direction::East] }
+            Self::Ground | Self::Unknown => { &[] }
+        }
+    }
+
+    /// Returns the direction output vector depending on the tile type and the direction input vector
+    fn get_pipe_redirection(&self, direction: &Direction) -> Result<Coords, String> {
+        match *self {
+            Self::Ground => { Err(format!("No connection allowed from a {:?} tile...", self)) }
+            Self::NorthSouth => {
+                match *direction {
+                    Direction::North => { Ok(Direction::North.offset()) }
+                    Direction::South => { Ok(Direction::South.offset()) }
+                    Direction::West => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::East => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                }
+            }
+            Self::EastWest => {
+                match *direction {
+                    Direction::North => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::South => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::West => { Ok(Direction::West.offset()) }
+                    Direction::East => { Ok(Direction::East.offset()) }
+                }
+            }
+            Self::NorthEast => {
+                match *direction {
+                    Direction::North => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::South => { Ok(Direction::East.offset()) }
+                    Direction::West => { Ok(Direction::North.offset()) }
+                    Direction::East => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                }
+            }
+            Self::NorthWest => {
+                match *direction {
+                    Direction::North => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::South => { Ok(Direction::West.offset()) }
+                    Direction::West => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::East => { Ok(Direction::North.offset()) }
+                }
+            }
+            Self::SouthWest => {
+                match *direction {
+                    Direction::North => { Ok(Direction::West.offset()) }
+                    Direction::South => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::West => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::East => { Ok(Direction::South.offset()) }
+                }
+            }
+            Self::SouthEast => {
+                match *direction {
+                    Direction::North => { Ok(Direction::East.offset()) }
+                    Direction::South => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                    Direction::West => { Ok(Direction::South.offset()) }
+                    Direction::East => { Err(format!("No connection allowed from {:?} to a {:?} tile...", direction, self)) }
+                }
+            }
+            Self::Unknown => { Err(format!("No connection allowed from a {:?} tile...", self)) }
+        }
+    }
+    
+    /// Using the PIP algorithm we will count how much boundary we cross along a vertical ray, going up or down from the point depending on its row (see `count_borders`), thus, the NorthSouth runs along the ray and shouldn't be considered as a border crossed.
+    /// Only the corners going East are counted: a 'F' followed by a 'J' crosses the loop once, while a 'F' followed by a 'L' only touches it
+    fn cross_bounds(&self) -> Result<bool, String> {
+        match self {
+            &Self::EastWest | Self::NorthEast | Self::SouthEast => { Ok(true) }
+            &Self::Unknown => { Err("Shouldn't encounter Unknown tiles at this point...".to_string()) }
+            _ => { Ok(false) }
+        }
+    }
+
+}
+
+/// (0, 0) located top left corner of the map
+type Coords = Point2<i64>;
+
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+enum TileStatus {
+    /// Is the starting tile
+    Start,
+    /// Is part of the loop
+    Loop,
+    /// Is contained by the loop
+    In,
+    /// Is NOT contained by the loop
+    Out,
+    /// Undefined status 
+    Undefined
+}
+
+#[derive(Eq, Hash, PartialEq, Debug, Clone, Copy)]
+struct Tile {
+    /// (0, 0) located top left corner of the map
+    coords: Coords,
+    /// The type of the tile
+    tile_type: TileType,
+    /// The status of the tile [Start, Loop, In, Out, Undefined]
+    status: TileStatus
+}
+
+impl Tile {
+    fn new(coords: Coords, tile_type: TileType, status: TileStatus) -> Self {
+        Self {
+            coords,
+            tile_type,
+            status
+        }
+    }
+
+    /// Returns the tile char representing the pipe
+    fn get_char(&self) -> char {
+        self.tile_type.get_char()
+    }
+
+    /// Returns the next coords based on the last tile and the current
+    fn get_next_coords(&self, last_tile: &Tile) -> Result<Coords, String> {
+        let diff_coords = self.coords - last_tile.coords;
+        let direction = match Direction::from_offset(diff_coords) {
+            Some(val) => val,
+            None => {
+                let err_msg = format!("[Error while getting computing the input direction (diff_coords: {:?})]", diff_coords);
+                return Err(err_msg);
+            }
+        };
+
+        match self.tile_type.get_pipe_redirection(&direction) {
+            Ok(val) => {
+                Ok(self.coords + val)
+            }
+            Err(error) => {
+                let err_msg = format!("[Error while getting the pipe redirection: {error}]");
+                Err(err_msg)
+            }
+        }
+    }
+}
+
+
+fn get_input(input: &Input) -> Result<PipeMap, InputError> {
+    // Get the content
+    let grid: Grid<char> = Grid::parse(input)?;
+
+    // Parse the content into tiles
+    let mut start_coords = Coords::new(0, 0);
+    let mut tiles_vec: Vec<Tile> = Vec::with_capacity(grid.width() * grid.height());
+    for ((index_x, index_y), tile_char) in grid.iter() {
+        let coords = Coords::new(index_x as i64, index_y as i64);
+        match tile_char {
+            '.' => { tiles_vec.push(Tile::new(coords, TileType::Ground, TileStatus::Undefined)); }
+            '|' => { tiles_vec.push(Tile::new(coords, TileType::NorthSouth,  TileStatus::Undefined)); }
+            '-' => { tiles_vec.push(Tile::new(coords, TileType::EastWest,  TileStatus::Undefined)); }
+            'L' => { tiles_vec.push(Tile::new(coords, TileType::NorthEast,  TileStatus::Undefined)); }
+            'J' => { tiles_vec.push(Tile::new(coords, TileType::NorthWest,  TileStatus::Undefined)); }
+            '7' => { tiles_vec.push(Tile::new(coords, TileType::SouthWest,  TileStatus::Undefined)); }
+            'F' => { tiles_vec.push(Tile::new(coords, TileType::SouthEast,  TileStatus::Undefined)); }
+            'S' => { tiles_vec.push(Tile::new(coords, TileType::Unknown,  TileStatus::Start)); start_coords = coords; }
+            _   => { return Err(input.error(index_y + 1, format!("Unknown tile char: '{tile_char}'"))); }
+        }
+    }
+    let mut tiles: Grid<Tile> = match Grid::new(grid.width(), grid.height(), tiles_vec) {
+        Ok(val) => val,
+        Err(error) => {
+            return Err(input.error(1, error));
+        }
+    };
+
+    match init_start_tile(&mut tiles, &start_coords) {
+        Ok(_) => {}
+        Err(error) => {
+            return Err(input.error(start_coords.y as usize + 1, format!("Error while initializing the starting tile: {error}")));
+        }
+    }
+    
+    Ok(PipeMap { tiles, start: start_coords })
+}
+
+/// Returns the tile at the given coords, None if outside of the map
+fn get_tile<'a>(tiles: &'a Grid<Tile>, coords: &Coords) -> Option<&'a Tile> {
+    let (x, y) = tiles.position(coords.x, coords.y)?;
+    tiles.get(x, y)
+}
+
+fn get_tile_mut<'a>(tiles: &'a mut Grid<Tile>, coords: &Coords) -> Option<&'a mut Tile> {
+    let (x, y) = tiles.position(coords.x, coords.y)?;
+    tiles.get_mut(x, y)
+}
+
+/// Returns the visualizer of the map, if the animation was asked for
+fn start_visualizer(visualization: Option<Visualization>, tiles: &Grid<Tile>) -> Result<Option<Visualizer>, String> {
+    match visualization {
+        Some(config) => Ok(Some(Visualizer::new(config, tiles)?)),
+        None => Ok(None)
+    }
+}
+
+/// Initialize the start tile type based on its surroundings
+fn init_start_tile(tiles: &mut Grid<Tile>, start_coords: &Coords) -> Result<(), String> {
+    // Get surrouding tiles type, the ones outside of the map can't be connected
+    let start_tile = match get_tile(tiles, start_coords) {
+        Some(val) => *val,
+        None => {
+            return Err(format!("No start tile found at {:?}", start_coords));
+        }
+    };
+    let is_connected = |offset: Coords| -> bool {
+        get_tile(tiles, &(*start_coords - offset)).is_some_and(|tile| tile.get_next_coords(&start_tile).is_ok())
+    };
+    let north_connected = is_connected(Coords::new(0, -1));
+    let south_connected = is_connected(Coords::new(0, 1));
+    let west_connected = is_connected(Coords::new(-1, 0));
+    let east_connected = is_connected(Coords::new(1, 0));
+
+    let start_tile_type: TileType = 
+        if north_connected && south_connected { TileType::NorthSouth }
+        else if north_connected && east_connected { TileType::SouthWest }
+        else if north_connected && west_connected { TileType::SouthEast }
+        else if south_connected && east_connected { TileType::NorthWest }
+        else if south_connected && west_connected { TileType::NorthEast }
+        else if east_connected && west_connected { TileType::EastWest }
+        else { return Err(format!("Couldn't initialize the Start tile: (north: {north_connected}, south: {south_connected}, west: {west_connected}, east: {east_connected}, ")); };
+
+    if let Some(start_tile) = get_tile_mut(tiles, start_coords) {
+        start_tile.tile_type = start_tile_type;
+    }
+
+    Ok(())
+}
+
+
+/// Follows pipes from starting coords, and returns the coords of the loop in the walking order, starting with the start coords
+fn follow_pipes(tiles: &mut Grid<Tile>, start_coords: &Coords, mut visualizer: Option<&mut Visualizer>) -> Result<Vec<Coords>, String> {
+    //  Result variables
+    let mut steps: u64 = 0;
+    let mut loop_coords: Vec<Coords> = vec![*start_coords];
+
+    // Run variables
+    let mut current_tile = *get_tile(tiles, start_coords).unwrap();
+    let mut last_tile: Tile;
+    // Can't get enough ident :)
+    match current_tile.tile_type.get_pipe_redirection(&Direction::North) {
+        Ok(val) => { last_tile = *get_tile(tiles, &(*start_coords + val)).unwrap(); }
+        Err(_) => {
+            match current_tile.tile_type.get_pipe_redirection(&Direction::East) {
+                Ok(val) => { last_tile = *get_tile(tiles, &(*start_coords + val)).unwrap(); }
+                Err(_) => {
+                    match current_tile.tile_type.get_pipe_redirection(&Direction::South) {
+                        Ok(val) => { last_tile = *get_tile(tiles, &(*start_coords + val)).unwrap(); }
+                        Err(_) => {
+                            match current_tile.tile_type.get_pipe_redirection(&Direction::West) {
+                                Ok(val) => { last_tile = *get_tile(tiles, &(*start_coords + val)).unwrap(); }
+                                Err(_) => {
+                                    return Err("Couldn't find any direction allowing to initiate the follow pipe".to_string());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    while current_tile.coords != *start_coords || steps == 0 {
+        match current_tile.get_next_coords(&last_tile) {
+            Ok(val) => {
+                last_tile = current_tile;
+                let mut_tile = match get_tile_mut(tiles, &val) {
+                    Some(tile) => tile,
+                    None => {
+                        return Err(format!("[The pipe at {:?} leads outside of the map]", current_tile.coords));
+                    }
+                };
+                if mut_tile.status != TileStatus::Start {
+                    mut_tile.status = TileStatus::Loop;
+                }
+                current_tile = *mut_tile;
+                if val != *start_coords {
+                    loop_coords.push(val);
+                }
+                steps += 1;
+            }
+            Err(error) => {
+                let err_msg = format!("[Error while getting next tile coords (steps: {steps},  last_tile: {:?}, current_tile: {:?}): {error}]", last_tile, current_tile);
+                return Err(err_msg);
+            }
+        }
+        if let Some(visualizer) = visualizer.as_deref_mut() {
+            visualizer.step(tiles, &current_tile.coords)?;
+        }
+    }
+
+    Ok(loop_coords)
+}
+
+/// Returns a Vec containing coords of all the points of the maze that aren't part of the loop
+fn get_all_points(tiles: &Grid<Tile>) -> Vec<Coords> {
+    let mut res = Vec::<Coords>::new();
+    for (_, tile) in tiles.iter() {
+        // Unconnected pipes can be enclosed as well
+        if tile.status != TileStatus::Loop && tile.status != TileStatus::Start {
+            res.push(tile.coords);
+        }
+    }
+    res
+}
+
+fn count_borders(tiles: &Grid<Tile>, point_coords: &Coords) -> Result<usize, String> {
+    let height = tiles.height() as i64;
+
+    // Move Up or Down, towards the closest edge of the map
+    if point_coords.y >= height / 2 {
+        __count_borders(tiles, point_coords, point_coords.y+1..height)
+    }
+    else {
+        __count_borders(tiles, point_coords, (0..point_coords.y).rev())
+    }
+}
+
+fn __count_borders(tiles: &Grid<Tile>, point_coords: &Coords, range_iter: impl Iterator<Item = i64>) -> Result<usize, String> {
+    let mut res: usize = 0;
+    for y in range_iter {
+        let upper_coords = Coords::new(point_coords.x, y);
+        match get_tile(tiles, &upper_coords) {
+            Some(upper_tile) => {
+                if upper_tile.status == TileStatus::Loop || upper_tile.status == TileStatus::Start {
+                    match upper_tile.tile_type.cross_bounds(){
+                        Ok(crossed_bound) => { 
+                            if crossed_bound {
+                                res += 1;
+                            }
+                        }
+                        Err(err_msg) => {
+                            return Err(format!("Error while getting cross bounds of {:?}. Error: {:?}", upper_tile, err_msg));
+                        }
+                    }
+                }
+            }
+            None => { return Err(format!("Error while getting tile of {:?}", upper_coords)); }
+        }
+    }
+
+    Ok(res)
+}
+
+/// Returns the number of tiles enclosed by the loop, by ray casting from every tile not in the loop
+fn count_enclosed_tiles(pipe_map: &PipeMap, visualization: Option<Visualization>) -> Result<usize, String> {
+    let (_, in_tile) = classify_tiles(pipe_map, visualization)?;
+    Ok(in_tile)
+}
+
+/// Sets the status of every tile (Loop, In or Out), and returns the classified map with its number of In tiles
+fn classify_tiles(pipe_map: &PipeMap, visualization: Option<Visualization>) -> Result<(Grid<Tile>, usize), String> {
+    let mut tiles = pipe_map.tiles.clone();
+    let mut visualizer = start_visualizer(visualization, &tiles)?;
+
+    if let Err(error) = follow_pipes(&mut tiles, &pipe_map.start, visualizer.as_mut()) {
+        return Err(format!("Error while following pipe: Error: {error}"));
+    }
+
+    let all_points = get_all_points(&tiles);
+
+    let mut in_tile: usize = 0;
+
+    for point_coords in all_points {
+        match count_borders(&tiles, &point_coords) {
+            Ok(count) => {
+                match get_tile_mut(&mut tiles, &point_coords) {
+                    Some(tile) => { 
+                        if count % 2 == 0 {
+                            tile.status = TileStatus::Out;
+                        }
+                        else {
+                            tile.status = TileStatus::In;
+                            in_tile += 1;
+                        }
+                    }
+                    None => { 
+                        return Err(format!("Error while getting tile: {:?}", point_coords));
+                    }
+                }
+            }
+            Err(err_msg) => {
+                return Err(format!("Error while following pipe: Error: {err_msg}"));
+            }
+        }
+        if let Some(visualizer) = visualizer.as_mut() {
+            visualizer.step(&tiles, &point_coords)?;
+        }
+    }
+
+    if let Some(visualizer) = visualizer.as_mut() {
+        visualizer.finish(&tiles)?;
+    }
+    Ok((tiles, in_tile))
+}
+
+/// Returns the number of tiles enclosed by the loop, from the coords of the loop in the walking order.
+/// The shoelace formula gives the area of the loop polygon, and Pick's theorem (area = inside + boundary / 2 - 1)
+/// the number of tiles inside it, the loop tiles being the boundary points
+fn count_enclosed_tiles_shoelace(pipe_map: &PipeMap, visualization: Option<Visualization>) -> Result<usize, String> {
+    let mut tiles = pipe_map.tiles.clone();
+    let mut visualizer = start_visualizer(visualization, &tiles)?;
+    let loop_coords: Vec<Coords> = match follow_pipes(&mut tiles, &pipe_map.start, visualizer.as_mut()) {
+        Ok(val) => val,
+        Err(error) => {
+            return Err(format!("Error while following pipe: Error: {error}"));
+        }
+    };
+    if let Some(visualizer) = visualizer.as_mut() {
+        visualizer.finish(&tiles)?;
+    }
+
+    let inside = interior_points(polygon_double_area(&loop_coords), loop_coords.len() as i64);
+    match usize::try_from(inside) {
+        Ok(val) => Ok(val),
+        Err(error) => Err(format!("Invalid number of enclosed tiles ({inside}): {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shoelace_agrees_with_ray_casting() {
+        for content in [include_str!("../files/input_debug_1.txt"), include_str!("../files/input_debug_2.txt"), include_str!("../files/input.txt")] {
+            let pipe_map = Day10::parse(content).unwrap();
+            assert!(Day10::part2_with(&pipe_map, &Day10Options { area: AreaMethod::CrossCheck, ..Default::default() }).is_ok());
+        }
+    }
+
+    #[test]
+    fn animation_options_imply_visualize() {
+        let args: Vec<String> = ["--area", "ray-cast", "--fps", "30"].iter().map(|arg| arg.to_string()).collect();
+        let options = Day10::parse_options(&args).unwrap();
+        assert_eq!(options.area, AreaMethod::RayCast);
+        assert_eq!(options.visualization, Some(Visualization { fps: 30, steps_per_frame: 1 }));
+        assert!(Day10::parse_options(&["--steps-per-frame".to_string()]).is_err());
+    }
+
+    #[test]
+    fn exported_images_cover_the_map() {
+        let pipe_map = Day10::parse(include_str!("../files/input_debug_1.txt")).unwrap();
+        let (tiles, _) = classify_tiles(&pipe_map, None).unwrap();
+        let svg = export::to_svg(&tiles);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect").count(), 1 + tiles.iter().filter(|(_, tile)| tile.status != TileStatus::Loop).count());
+
+        let png = export::to_png(&tiles);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()) as usize, 8 * tiles.width());
+    }
+}
@@ -0,0 +1,5 @@
+use d10::Day10;
+
+fn main() -> Result<(), i8> {
+    aoc_solution::run_main_with_options::<Day10>(env!("CARGO_MANIFEST_DIR"))
+}
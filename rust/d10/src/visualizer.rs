@@ -0,0 +1,173 @@
+use std::{io::{stdout, Stdout, Write}, thread, time::{Duration, Instant}};
+
+use ansi_term::Colour;
+use crossterm::{cursor, terminal, QueueableCommand};
+
+use aoc_grid::Grid;
+
+use super::{Coords, Tile, TileStatus};
+
+/// How the walk along the loop and the in/out classification are animated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visualization {
+    /// Maximum number of frames drawn per second
+    pub fps: u32,
+    /// Number of tiles walked or classified between two frames
+    pub steps_per_frame: usize
+}
+
+impl Default for Visualization {
+    fn default() -> Self {
+        Self {
+            fps: 60,
+            steps_per_frame: 1
+        }
+    }
+}
+
+/// Returns the colour of a tile status, None for the tiles not classified yet
+fn get_colour(status: TileStatus) -> Option<Colour> {
+    match status {
+        TileStatus::Start => Some(Colour::Purple),
+        TileStatus::Loop => Some(Colour::Red),
+        TileStatus::In => Some(Colour::Green),
+        TileStatus::Out => Some(Colour::Cyan),
+        TileStatus::Undefined => None
+    }
+}
+
+fn paint(huchar: char, status: TileStatus) -> String {
+    match get_colour(status) {
+        Some(colour) => colour.paint(huchar.to_string()).to_string(),
+        None => huchar.to_string()
+    }
+}
+
+/// Draws the map in the terminal, only the part fitting in the viewport, and only the tiles that changed since the last frame
+pub struct Visualizer {
+    config: Visualization,
+    stdout: Stdout,
+    /// Top left corner of the viewport on the map
+    origin: Coords,
+    view_width: usize,
+    view_height: usize,
+    /// What is currently displayed in each cell of the viewport, None if it has to be redrawn
+    displayed: Vec<Option<(char, TileStatus)>>,
+    steps: usize,
+    last_frame: Instant
+}
+
+impl Visualizer {
+    /// Clears the terminal, the viewport being as large as the terminal allows (a line is kept for the legend and one for the answer)
+    pub fn new(config: Visualization, tiles: &Grid<Tile>) -> Result<Self, String> {
+        let (columns, rows) = terminal::size().unwrap_or((tiles.width() as u16, tiles.height() as u16 + 2));
+        let view_width = tiles.width().min(columns as usize).max(1);
+        let view_height = tiles.height().min((rows as usize).saturating_sub(2)).max(1);
+
+        let mut visualizer = Self {
+            config,
+            stdout: stdout(),
+            origin: Coords::new(0, 0),
+            view_width,
+            view_height,
+            displayed: vec![None; view_width * view_height],
+            steps: 0,
+            last_frame: Instant::now()
+        };
+        if let Err(error) = visualizer.stdout.queue(terminal::Clear(terminal::ClearType::All)) {
+            return Err(format!("Error while clearing the terminal: {error}"));
+        }
+        Ok(visualizer)
+    }
+
+    /// Called after each tile walked or classified, draws a frame every `steps_per_frame` calls
+    pub fn step(&mut self, tiles: &Grid<Tile>, focus: &Coords) -> Result<(), String> {
+        self.steps += 1;
+        if !self.steps.is_multiple_of(self.config.steps_per_frame.max(1)) {
+            return Ok(());
+        }
+
+        // Wait for the next frame
+        let frame_duration = Duration::from_secs(1) / self.config.fps.max(1);
+        let elapsed = self.last_frame.elapsed();
+        if elapsed < frame_duration {
+            thread::sleep(frame_duration - elapsed);
+        }
+        self.last_frame = Instant::now();
+
+        self.scroll_to(tiles, focus);
+        self.draw(tiles)
+    }
+
+    /// Draws the last frame, and moves the cursor below the map and its legend
+    pub fn finish(&mut self, tiles: &Grid<Tile>) -> Result<(), String> {
+        self.draw(tiles)?;
+        let res = self.stdout.queue(cursor::MoveTo(0, self.view_height as u16 + 1))
+            .and_then(|stdout| stdout.flush());
+        match res {
+            Ok(_) => Ok(()),
+            Err(error) => Err(format!("Error while writing to the terminal: {error}"))
+        }
+    }
+
+    /// Moves the viewport so the focus is centered, once it gets within a quarter of the viewport from its border
+    fn scroll_to(&mut self, tiles: &Grid<Tile>, focus: &Coords) {
+        let scroll_axis = |origin: i64, focus: i64, view: usize, size: usize| -> i64 {
+            let margin = (view / 4) as i64;
+            if focus >= origin + margin && focus < origin + view as i64 - margin {
+                return origin;
+            }
+            (focus - view as i64 / 2).clamp(0, (size - view) as i64)
+        };
+        let origin = Coords::new(
+            scroll_axis(self.origin.x, focus.x, self.view_width, tiles.width()),
+            scroll_axis(self.origin.y, focus.y, self.view_height, tiles.height())
+        );
+        if origin != self.origin {
+            // Every cell shows another tile now
+            self.origin = origin;
+            self.displayed.fill(None);
+        }
+    }
+
+    /// Redraws the cells of the viewport whose tile changed, and the legend
+    fn draw(&mut self, tiles: &Grid<Tile>) -> Result<(), String> {
+        let res = self.queue_frame(tiles).and_then(|_| self.stdout.flush());
+        match res {
+            Ok(_) => Ok(()),
+            Err(error) => Err(format!("Error while writing to the terminal: {error}"))
+        }
+    }
+
+    fn queue_frame(&mut self, tiles: &Grid<Tile>) -> std::io::Result<()> {
+        let redraw_legend = self.displayed.iter().all(|cell| cell.is_none());
+
+        for view_y in 0..self.view_height {
+            for view_x in 0..self.view_width {
+                let (x, y) = (self.origin.x as usize + view_x, self.origin.y as usize + view_y);
+                let tile = match tiles.get(x, y) {
+                    Some(val) => val,
+                    None => {
+                        continue;
+                    }
+                };
+                let cell = Some((tile.get_char(), tile.status));
+                let displayed = &mut self.displayed[view_y * self.view_width + view_x];
+                if *displayed != cell {
+                    *displayed = cell;
+                    self.stdout.queue(cursor::MoveTo(view_x as u16, view_y as u16))?;
+                    write!(self.stdout, "{}", paint(tile.get_char(), tile.status))?;
+                }
+            }
+        }
+
+        if redraw_legend {
+            self.stdout.queue(cursor::MoveTo(0, self.view_height as u16))?;
+            self.stdout.queue(terminal::Clear(terminal::ClearType::CurrentLine))?;
+            write!(self.stdout, "{} start  {} loop  {} in  {} out  | viewport ({}, {}) of {}x{}",
+                paint('■', TileStatus::Start), paint('■', TileStatus::Loop), paint('■', TileStatus::In), paint('■', TileStatus::Out),
+                self.origin.x, self.origin.y, tiles.width(), tiles.height())?;
+        }
+        Ok(())
+    }
+}